@@ -1,36 +1,534 @@
-use rpn_core::operation::{OpStack, OperationError, add, subtract, divide, remainder, multiply, rotate, square, copy};
+use rpn_core::number::{
+    AngleMode, Complex, Coordinate, Decimal, Dimension, Integer, Interval, Modular, Number,
+    NumberConvert, Power, Quantity, Rational, Rounding, RoundingConvert, Saturating, Value,
+    Wrapping,
+};
+use rpn_core::operation::{
+    Context, OpStack, OperationError, abs, acos, acosh, add, and, argument, arithmetic_shift_right,
+    asin, asinh, atan, atanh, ceil, clamp, clear, compare, conjugate, convert, copy, cos, cosh,
+    count_ones, cross, depth, divide, dot, drop, dropn, dupn, exp, floor, inverse, leading_zeros,
+    ln, log2, log10, make_complex, max, maximum, maximum_n, mean, mean_n, min, minimum, minimum_n,
+    modular_power, modulus, multiply, narrow, negate, nip, norm, not, or, over, pack2, pack3, pick,
+    power, product, product_n, remainder, roll, roll_down, root, rot3, rotate, rotate_bits_left,
+    rotate_bits_right, round, scale, shift_left, shift_right, sign, sin, sinh, split_complex, sqrt,
+    square, subtract, sum, sum_n, tan, tanh, to_degrees, to_float, to_radians, to_rational,
+    trailing_zeros, truncate, tuck, unpack, xor,
+};
+use rpn_core::radix::{self, RadixError, RadixFormat};
+use rpn_core::register::Registers;
 use rpn_core::stack::Stack;
+use rpn_core::statistics::{Deviation, Statistics};
+use rpn_std::notation::{self, Notation};
+use rpn_std::number::{BigInt, BigRational};
+use rpn_std::register::MapRegisters;
 use rpn_std::stack::VecStack;
+use std::borrow::Cow;
+use std::env;
+use std::fmt::Display;
 use std::io;
 use std::io::Write;
+use std::str::FromStr;
 
-type N = i32;
-type S = VecStack<N>;
+type S<N> = VecStack<N>;
+type Operation<N> = fn(OpStack<S<N>>) -> Result<OpStack<S<N>>, OperationError>;
+type ArgumentOperation<N> = fn(OpStack<S<N>>, &str) -> Result<OpStack<S<N>>, OperationError>;
+type Statistic<N> = fn(&Statistics<N>) -> Result<N, OperationError>;
+type StatisticsUpdate<N> =
+    fn(&mut Statistics<N>, OpStack<S<N>>) -> Result<OpStack<S<N>>, OperationError>;
+
+/// Runs with the integer type named by the second argument wrapped in an overflow policy,
+/// `i32` when there is none
+macro_rules! run_integer {
+    ($Policy:ident) => {
+        match env::args().nth(2).as_deref() {
+            None | Some("i32") => run::<$Policy<i32>>(),
+            Some("u8") => run::<$Policy<u8>>(),
+            Some("u16") => run::<$Policy<u16>>(),
+            Some("u32") => run::<$Policy<u32>>(),
+            Some("u64") => run::<$Policy<u64>>(),
+            Some("u128") => run::<$Policy<u128>>(),
+            Some("i8") => run::<$Policy<i8>>(),
+            Some("i16") => run::<$Policy<i16>>(),
+            Some("i64") => run::<$Policy<i64>>(),
+            Some("i128") => run::<$Policy<i128>>(),
+            Some(integer) => {
+                eprintln!("Unknown integer type: {integer}");
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Runs modulo the second argument, which can only be one of a few moduli as the modulus is
+/// part of the type
+macro_rules! run_modular {
+    ($($M:literal),*) => {
+        match env::args().nth(2).as_deref() {
+            $(Some(stringify!($M)) => run::<Modular<$M>>(),)*
+            _ => {
+                eprintln!(concat!("Modulus must be one of:", $(" ", stringify!($M)),*));
+                Ok(())
+            }
+        }
+    };
+}
 
 fn main() -> Result<(), io::Error> {
-    let mut environment = CliEnvironment::default();
+    match env::args().nth(1).as_deref() {
+        None => run::<i32>(),
+        Some("--wrapping") => run_integer!(Wrapping),
+        Some("--saturating") => run_integer!(Saturating),
+        Some("--float") => run::<f64>(),
+        Some("--rational") => run::<Rational<i64>>(),
+        Some("--modular") => run_modular!(2, 7, 11, 12, 13, 26, 97, 256, 65537, 1000000007),
+        Some("--big-integer") => run::<BigInt>(),
+        Some("--big-rational") => run::<BigRational>(),
+        Some("--complex") => run::<Complex<f64>>(),
+        Some("--vector") => run::<Coordinate<f64>>(),
+        Some("--decimal") => run::<Decimal<2>>(),
+        Some("--interval") => run::<Interval<f64>>(),
+        Some("--units") => run::<Quantity<f64>>(),
+        Some("--mixed") => run::<Value>(),
+        Some("--mixed-float") => run::<Value<false>>(),
+        Some(mode) => {
+            eprintln!("Unknown mode: {mode}");
+            Ok(())
+        }
+    }
+}
+
+/// Number of fractional digits shown next to fractions
+const DECIMAL_DIGITS: usize = 10;
+
+/// Number type the calculator can run with
+trait CliNumber: Number + FromStr + Display {
+    /// Whether trigonometric operations are available, making the angle mode worth showing
+    const ANGLES: bool = false;
+    /// Whether integers can be displayed in other radices, making the radix worth showing
+    const RADIX: bool = false;
+    /// Whether floats can be displayed in other notations, making the notation worth showing
+    const NOTATION: bool = false;
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn format_in(&self, _formatting: Formatting) -> String {
+        self.format()
+    }
+
+    /// Parses a number to push, once input is neither a command nor a radix literal
+    fn parse_number(input: &str) -> Option<Self> {
+        input.parse().ok()
+    }
+
+    /// Number pushed along with the word after it, when that word completes it
+    fn with_suffix(&self, _suffix: &str) -> Option<Self> {
+        None
+    }
+
+    /// Operations only available for this number type
+    fn operation(_input: &str) -> Option<Operation<Self>> {
+        None
+    }
+
+    /// Operations only available for this number type taking an argument from the next word
+    fn argument_operation(_input: &str) -> Option<ArgumentOperation<Self>> {
+        None
+    }
+
+    /// Statistics commands, only available for numbers whose division does not truncate
+    fn statistics(_input: &str) -> Option<StatisticsCommand<Self>> {
+        None
+    }
+}
+
+/// Operations of numbers that can be raised to powers
+fn power_operation<N: CliNumber + Power>(input: &str) -> Option<Operation<N>> {
+    match input {
+        "^" | "power" => Some(power),
+        "sqrt" => Some(sqrt),
+        "root" => Some(root),
+        _ => None,
+    }
+}
+
+/// Statistics of the accumulated data points
+fn statistics_command<N: CliNumber>(input: &str) -> Option<StatisticsCommand<N>> {
+    let statistic: Statistic<N> = match input {
+        "stat+" | "Σ+" => return Some(StatisticsCommand::Update(Statistics::accumulate)),
+        "stat-" | "Σ-" => return Some(StatisticsCommand::Update(Statistics::deduct)),
+        "stat2+" | "Σ2+" => return Some(StatisticsCommand::Update(Statistics::accumulate_pair)),
+        "stat2-" | "Σ2-" => return Some(StatisticsCommand::Update(Statistics::deduct_pair)),
+        "statclr" => return Some(StatisticsCommand::Clear),
+        "stats" => return Some(StatisticsCommand::Show),
+        "ŷ" | "yhat" => return Some(StatisticsCommand::Predict),
+        "x̄" | "xmean" => Statistics::mean_x,
+        "ȳ" | "ymean" => Statistics::mean_y,
+        "var" => |s| s.variance(Deviation::Sample),
+        "pvar" => |s| s.variance(Deviation::Population),
+        "slope" => Statistics::slope,
+        "intercept" => Statistics::intercept,
+        _ => return None,
+    };
+    Some(StatisticsCommand::Push(statistic))
+}
+
+/// Statistics taking square roots, of numbers that can be raised to powers
+fn deviation_command<N: CliNumber + Power>(input: &str) -> Option<StatisticsCommand<N>> {
+    let statistic: Statistic<N> = match input {
+        "sdev" => |s| s.standard_deviation(Deviation::Sample),
+        "psdev" => |s| s.standard_deviation(Deviation::Population),
+        "corr" => Statistics::correlation,
+        _ => return statistics_command(input),
+    };
+    Some(StatisticsCommand::Push(statistic))
+}
+
+/// Operations on the bits of fixed-width integers
+fn integer_operation<N: CliNumber + Integer + Power>(input: &str) -> Option<Operation<N>> {
+    let operation: Operation<N> = match input {
+        "&" | "and" => and,
+        "|" | "or" => or,
+        "xor" => xor,
+        "~" | "not" => not,
+        "<<" | "shl" => shift_left,
+        ">>" | "shr" => shift_right,
+        "sar" => arithmetic_shift_right,
+        "rol" => rotate_bits_left,
+        "ror" => rotate_bits_right,
+        "popcount" => count_ones,
+        "clz" => leading_zeros,
+        "ctz" => trailing_zeros,
+        _ => return power_operation(input),
+    };
+    Some(operation)
+}
+
+/// Operations rounding numbers with a fractional part to integers
+fn rounding_operation<N>(input: &str) -> Option<Operation<N>>
+where
+    N: CliNumber + RoundingConvert<i64>,
+    i64: NumberConvert<N>,
+{
+    let operation: Operation<N> = match input {
+        "floor" => floor,
+        "ceil" => ceil,
+        "round" => round,
+        "trunc" => truncate,
+        _ => return None,
+    };
+    Some(operation)
+}
+
+/// Casts checking that numbers fit in a narrower integer type, named after the type
+fn narrow_operation<N>(input: &str) -> Option<Operation<N>>
+where
+    N: CliNumber
+        + NumberConvert<i8>
+        + NumberConvert<i16>
+        + NumberConvert<i32>
+        + NumberConvert<u8>
+        + NumberConvert<u16>
+        + NumberConvert<u32>,
+    i8: NumberConvert<N>,
+    i16: NumberConvert<N>,
+    i32: NumberConvert<N>,
+    u8: NumberConvert<N>,
+    u16: NumberConvert<N>,
+    u32: NumberConvert<N>,
+{
+    let operation: Operation<N> = match input {
+        "i8" => narrow::<_, i8>,
+        "i16" => narrow::<_, i16>,
+        "i32" => narrow::<_, i32>,
+        "u8" => narrow::<_, u8>,
+        "u16" => narrow::<_, u16>,
+        "u32" => narrow::<_, u32>,
+        _ => return None,
+    };
+    Some(operation)
+}
+
+impl CliNumber for i32 {
+    const RADIX: bool = true;
+
+    fn format_in(&self, formatting: Formatting) -> String {
+        formatting.radix.display(self).to_string()
+    }
+
+    fn parse_number(input: &str) -> Option<Self> {
+        notation::parse(input)
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        integer_operation(input).or_else(|| narrow_operation(input))
+    }
+}
+
+impl CliNumber for f64 {
+    const ANGLES: bool = true;
+    const NOTATION: bool = true;
+
+    fn format_in(&self, formatting: Formatting) -> String {
+        formatting.notation.format(*self)
+    }
+
+    fn parse_number(input: &str) -> Option<Self> {
+        notation::parse(input)
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        let operation: Operation<Self> = match input {
+            "sin" => sin,
+            "cos" => cos,
+            "tan" => tan,
+            "asin" => asin,
+            "acos" => acos,
+            "atan" => atan,
+            "d→r" | "to_radians" => to_radians,
+            "r→d" | "to_degrees" => to_degrees,
+            "sinh" => sinh,
+            "cosh" => cosh,
+            "tanh" => tanh,
+            "asinh" => asinh,
+            "acosh" => acosh,
+            "atanh" => atanh,
+            "exp" => exp,
+            "ln" => ln,
+            "log" | "log10" => log10,
+            "log2" => log2,
+            _ => {
+                return power_operation(input)
+                    .or_else(|| rounding_operation(input))
+                    .or_else(|| narrow_operation(input));
+            }
+        };
+        Some(operation)
+    }
+
+    fn statistics(input: &str) -> Option<StatisticsCommand<Self>> {
+        deviation_command(input)
+    }
+}
+
+impl<T> CliNumber for Wrapping<T>
+where
+    Self: Integer + Power + FromStr + Display,
+{
+    const RADIX: bool = true;
+
+    fn format_in(&self, formatting: Formatting) -> String {
+        formatting.radix.display(self).to_string()
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        integer_operation(input)
+    }
+}
+
+impl<T> CliNumber for Saturating<T>
+where
+    Self: Integer + Power + FromStr + Display,
+{
+    const RADIX: bool = true;
+
+    fn format_in(&self, formatting: Formatting) -> String {
+        formatting.radix.display(self).to_string()
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        integer_operation(input)
+    }
+}
+
+impl CliNumber for BigInt {}
+
+impl<const M: u64> CliNumber for Modular<M> {
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        match input {
+            "inv" | "inverse" => Some(inverse),
+            _ => None,
+        }
+    }
+
+    fn argument_operation(input: &str) -> Option<ArgumentOperation<Self>> {
+        match input {
+            "powmod" => Some(|stack, exponent| {
+                let exponent = exponent
+                    .parse()
+                    .map_err(|_| OperationError::InvalidOperand)?;
+                modular_power(exponent)(stack)
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> CliNumber for Decimal<SCALE, R> {}
+
+impl<T: Number + FromStr + Display> CliNumber for Rational<T> {
+    fn format(&self) -> String {
+        if *self.denominator() == T::ONE {
+            self.to_string()
+        } else {
+            format!("{self} ≈ {}", self.decimal_expansion(DECIMAL_DIGITS))
+        }
+    }
+
+    fn statistics(input: &str) -> Option<StatisticsCommand<Self>> {
+        statistics_command(input)
+    }
+}
+
+impl<const EXACT: bool> CliNumber for Value<EXACT> {
+    fn format(&self) -> String {
+        match self {
+            Value::Rational(r) => r.format(),
+            value => value.to_string(),
+        }
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        match input {
+            "float" | "→float" => Some(to_float),
+            "frac" | "→frac" => Some(to_rational),
+            _ => rounding_operation(input).or_else(|| narrow_operation(input)),
+        }
+    }
+
+    fn statistics(input: &str) -> Option<StatisticsCommand<Self>> {
+        statistics_command(input)
+    }
+}
+
+impl CliNumber for Interval<f64> {
+    /// Rounds the midpoint to the first significant digit of the radius
+    fn format(&self) -> String {
+        match (self.midpoint(), self.radius()) {
+            (Ok(midpoint), Ok(radius)) if radius > 0. => {
+                let digits = (-radius.log10()).ceil().max(0.) as usize;
+                format!("{midpoint:.digits$} ± {radius:.digits$}")
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl CliNumber for Quantity<f64> {
+    /// Gives its unit to a plain number written apart from it, as in `100 km/h`
+    fn with_suffix(&self, suffix: &str) -> Option<Self> {
+        if self.unit().is_some() || self.dimension() != Dimension::NONE {
+            return None;
+        }
+        Quantity::with_unit(*self.value(), suffix.parse().ok()?).ok()
+    }
+
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        match input {
+            "convert" => Some(convert),
+            _ => None,
+        }
+    }
+
+    /// Converts to the unit named by the next word, as in `100 km/h → m/s`
+    fn argument_operation(input: &str) -> Option<ArgumentOperation<Self>> {
+        match input {
+            "to" | "→" => Some(|stack, unit| {
+                let target = unit.parse().map_err(|_| OperationError::InvalidOperand)?;
+                convert(stack.hold(target).push()?)
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl CliNumber for Complex<f64> {
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        match input {
+            "conj" | "conjugate" => Some(conjugate),
+            "abs" | "modulus" => Some(modulus),
+            "arg" | "argument" => Some(argument),
+            "complex" => Some(make_complex),
+            "split" => Some(split_complex),
+            _ => None,
+        }
+    }
+}
+
+impl CliNumber for Coordinate<f64> {
+    fn operation(input: &str) -> Option<Operation<Self>> {
+        match input {
+            "scale" => Some(scale),
+            "dot" => Some(dot),
+            "cross" => Some(cross),
+            "norm" => Some(norm),
+            "xy" | "pack2" => Some(pack2),
+            "xyz" | "pack3" => Some(pack3),
+            "unpack" => Some(unpack),
+            _ => None,
+        }
+    }
+}
+
+fn run<N: CliNumber>() -> Result<(), io::Error> {
+    let mut environment = CliEnvironment::<N>::default();
     let mut input = String::new();
     while !environment.exited {
+        if N::ANGLES {
+            print!("{} ", environment.context.angle_mode);
+        }
+        if N::RADIX && environment.formatting.radix != RadixFormat::DECIMAL {
+            print!("{} ", environment.formatting.radix);
+        }
+        if N::NOTATION && environment.formatting.notation != Notation::Standard {
+            print!("{} ", environment.formatting.notation);
+        }
         print!("> ");
         io::stdout().flush()?;
         io::stdin().read_line(&mut input)?;
-        for operation in input.split_whitespace().map(parse_input) {
-            match operation {
-                ParsedInput::Operation(o) => environment.evaluate(o),
-                ParsedInput::Push(n) => environment.push(n),
-                ParsedInput::Exit => {
-                    environment.exited = true;
-                }
-                ParsedInput::Unknown(o) => println!("Unknown command: {o}"),
-            }
-        }
+        environment.execute(&input);
         input.clear();
         environment.print();
     }
     Ok(())
 }
 
-fn parse_input(input: &str) -> ParsedInput {
+/// Word joined with the ones after it up to its closing parenthesis, so coordinates can be
+/// written with spaces as they are displayed, as in `(1, 2)`
+fn grouped<'a>(word: &'a str, words: &mut impl Iterator<Item = &'a str>) -> Cow<'a, str> {
+    if !word.starts_with('(') || word.contains(')') {
+        return Cow::Borrowed(word);
+    }
+    let mut group = word.to_string();
+    for word in words.by_ref() {
+        group.push_str(word);
+        if word.contains(')') {
+            break;
+        }
+    }
+    Cow::Owned(group)
+}
+
+fn parse_input<N: CliNumber>(input: &str) -> ParsedInput<N> {
+    if let Some(o) = N::operation(input) {
+        return ParsedInput::Operation(o);
+    }
+    if let Some(o) = N::argument_operation(input) {
+        return ParsedInput::ArgumentOperation(o);
+    }
+    if let Some(command) = N::statistics(input) {
+        return ParsedInput::Statistics(command);
+    }
+    if let Some(mode) = N::ANGLES.then(|| input.parse().ok()).flatten() {
+        return ParsedInput::AngleMode(mode);
+    }
+    if let Some(radix) = N::RADIX.then(|| parse_radix(input)).flatten() {
+        return radix;
+    }
+    if let Some(notation) = N::NOTATION.then(|| parse_notation(input)).flatten() {
+        return ParsedInput::Notation(notation);
+    }
     match input {
         "+" | "add" => ParsedInput::Operation(add),
         "-" | "subtract" => ParsedInput::Operation(subtract),
@@ -38,44 +536,310 @@ fn parse_input(input: &str) -> ParsedInput {
         "/" | "divide" => ParsedInput::Operation(divide),
         "%" | "remainder" => ParsedInput::Operation(remainder),
         "^2" | "square" => ParsedInput::Operation(square),
+        "neg" | "negate" => ParsedInput::Operation(negate),
+        "abs" => ParsedInput::Operation(abs),
         "rotate" => ParsedInput::Operation(rotate),
         "copy" => ParsedInput::Operation(copy),
+        "drop" => ParsedInput::Operation(drop),
+        "clear" => ParsedInput::Operation(clear),
+        "over" => ParsedInput::Operation(over),
+        "nip" => ParsedInput::Operation(nip),
+        "tuck" => ParsedInput::Operation(tuck),
+        "pick" => ParsedInput::Operation(pick),
+        "roll" => ParsedInput::Operation(roll),
+        "rolld" => ParsedInput::Operation(roll_down),
+        "rot3" => ParsedInput::Operation(rot3),
+        "dupn" => ParsedInput::Operation(dupn),
+        "dropn" => ParsedInput::Operation(dropn),
+        "depth" => ParsedInput::Operation(depth),
+        "sum" => ParsedInput::Operation(sum),
+        "sumn" => ParsedInput::Operation(sum_n),
+        "prod" | "product" => ParsedInput::Operation(product),
+        "prodn" => ParsedInput::Operation(product_n),
+        "minall" => ParsedInput::Operation(minimum),
+        "minn" => ParsedInput::Operation(minimum_n),
+        "maxall" => ParsedInput::Operation(maximum),
+        "maxn" => ParsedInput::Operation(maximum_n),
+        "mean" => ParsedInput::Operation(mean),
+        "meann" => ParsedInput::Operation(mean_n),
+        "min" => ParsedInput::Operation(min),
+        "max" => ParsedInput::Operation(max),
+        "cmp" | "compare" => ParsedInput::Operation(compare),
+        "sign" => ParsedInput::Operation(sign),
+        "clamp" => ParsedInput::Operation(clamp),
+        "sto" => ParsedInput::Register(RegisterCommand::Store),
+        "sto+" => ParsedInput::Register(RegisterCommand::StoreWith(Number::add)),
+        "sto-" => ParsedInput::Register(RegisterCommand::StoreWith(Number::subtract)),
+        "sto*" => ParsedInput::Register(RegisterCommand::StoreWith(Number::multiply)),
+        "sto/" => ParsedInput::Register(RegisterCommand::StoreWith(Number::divide)),
+        "rcl" => ParsedInput::Register(RegisterCommand::Recall),
+        "regs" => ParsedInput::ListRegisters,
+        "regclr" => ParsedInput::ClearRegisters,
         "exit" => ParsedInput::Exit,
-        _ => match input.parse() {
+        _ => match radix::parse(input) {
             Ok(n) => ParsedInput::Push(n),
-            Err(..) => ParsedInput::Unknown(input.to_owned()),
+            Err(RadixError::Prefix) => match N::parse_number(input) {
+                Some(n) => ParsedInput::Push(n),
+                None => ParsedInput::Unknown(input.to_owned()),
+            },
+            Err(e) => ParsedInput::Invalid(input.to_owned(), e),
         },
     }
 }
 
-enum ParsedInput {
-    Operation(fn(OpStack<S>) -> Result<OpStack<S>, OperationError>),
+/// Notation commands: `std`, or `fix=N`, `sci=N` and `eng=N` with the number of digits
+fn parse_notation(input: &str) -> Option<Notation> {
+    if input == "std" {
+        return Some(Notation::Standard);
+    }
+    let (command, digits) = input.split_once('=')?;
+    let digits = digits.parse().ok()?;
+    match command {
+        "fix" => Some(Notation::Fixed(digits)),
+        "sci" => Some(Notation::Scientific(digits)),
+        "eng" => Some(Notation::Engineering(digits)),
+        _ => None,
+    }
+}
+
+/// Display radix commands: `hex`, `dec`, `oct`, `bin` or `base=N`, along with `word=N`
+/// showing the two's complement of `N` bits and `word=off`
+fn parse_radix<N: Clone>(input: &str) -> Option<ParsedInput<N>> {
+    let radix = match input {
+        "hex" => 16,
+        "dec" => 10,
+        "oct" => 8,
+        "bin" => 2,
+        "word=off" => return Some(ParsedInput::WordSize(None)),
+        _ => {
+            let (command, value) = input.split_once('=')?;
+            let value = value.parse().ok()?;
+            return match command {
+                "base" => Some(ParsedInput::Radix(value)),
+                "word" => Some(ParsedInput::WordSize(Some(value))),
+                _ => None,
+            };
+        }
+    };
+    Some(ParsedInput::Radix(radix))
+}
+
+enum ParsedInput<N: Clone> {
+    Operation(Operation<N>),
+    /// Operation taking an argument from the next word
+    ArgumentOperation(ArgumentOperation<N>),
     Unknown(String),
+    Invalid(String, RadixError),
     Push(N),
+    Statistics(StatisticsCommand<N>),
+    /// Register command, taking the name of the register from the next word
+    Register(RegisterCommand<N>),
+    ListRegisters,
+    ClearRegisters,
+    AngleMode(AngleMode),
+    Radix(u32),
+    Notation(Notation),
+    WordSize(Option<u32>),
     Exit,
 }
 
-#[derive(Default)]
-struct CliEnvironment {
-    stack: S,
+enum StatisticsCommand<N: Clone> {
+    /// Adds or removes the data point on top of the stack
+    Update(StatisticsUpdate<N>),
+    Clear,
+    /// Prints the registers
+    Show,
+    /// Pushes a statistic of the data points
+    Push(Statistic<N>),
+    /// Replaces `x` on top of the stack with the `ŷ` predicted for it
+    Predict,
+}
+
+enum RegisterCommand<N> {
+    /// Stores the top of the stack
+    Store,
+    /// Replaces the register with the operation of it and the top of the stack
+    StoreWith(fn(N, N) -> Result<N, OperationError>),
+    /// Pushes the register
+    Recall,
+}
+
+/// How numbers on the stack are displayed
+#[derive(Clone, Copy, Default)]
+struct Formatting {
+    radix: RadixFormat,
+    notation: Notation,
+}
+
+struct CliEnvironment<N: Clone> {
+    stack: S<N>,
+    context: Context,
+    formatting: Formatting,
+    statistics: Statistics<N>,
+    registers: MapRegisters<N>,
     exited: bool,
 }
 
-impl CliEnvironment {
+impl<N: CliNumber> Default for CliEnvironment<N> {
+    fn default() -> Self {
+        Self {
+            stack: S::default(),
+            context: Context::default(),
+            formatting: Formatting::default(),
+            statistics: Statistics::default(),
+            registers: MapRegisters::default(),
+            exited: false,
+        }
+    }
+}
+
+impl<N: CliNumber> CliEnvironment<N> {
+    /// Evaluates every word of a line of input
+    fn execute(&mut self, input: &str) {
+        let mut words = input.split_whitespace().peekable();
+        while let Some(word) = words.next() {
+            let word = &grouped(word, &mut words);
+            match parse_input(word) {
+                ParsedInput::Operation(o) => self.evaluate(o),
+                ParsedInput::Push(n) => match words.peek().and_then(|&s| self.suffixed(&n, s)) {
+                    Some(n) => {
+                        words.next();
+                        self.push(n);
+                    }
+                    None => self.push(n),
+                },
+                ParsedInput::Statistics(command) => self.statistics(command),
+                ParsedInput::ArgumentOperation(o) => match words.next() {
+                    Some(argument) => self.evaluate(|stack| o(stack, argument)),
+                    None => println!("Missing argument: {word}"),
+                },
+                ParsedInput::Register(command) => match words.next() {
+                    Some(name) => self.register(command, name),
+                    None => println!("Missing register name: {word}"),
+                },
+                ParsedInput::ListRegisters => self.print_registers(),
+                ParsedInput::ClearRegisters => self.registers.clear(),
+                ParsedInput::AngleMode(mode) => self.context.angle_mode = mode,
+                ParsedInput::Radix(radix) => self.set_radix(radix),
+                ParsedInput::Notation(notation) => self.formatting.notation = notation,
+                ParsedInput::WordSize(word_size) => self.set_word_size(word_size),
+                ParsedInput::Exit => {
+                    self.exited = true;
+                }
+                ParsedInput::Unknown(o) => println!("Unknown command: {o}"),
+                ParsedInput::Invalid(o, e) => println!("{e}: {o}"),
+            }
+        }
+    }
+
+    /// Number completed by the word after it, unless that word is a command of its own, as the
+    /// unit symbol `min` is also the minimum operation
+    fn suffixed(&self, n: &N, suffix: &str) -> Option<N> {
+        match parse_input::<N>(suffix) {
+            ParsedInput::Push(_) => n.with_suffix(suffix),
+            _ => None,
+        }
+    }
+
     fn print(&self) {
         self.stack
             .iter()
             .enumerate()
-            .for_each(|(i, v)| println!("{i:2}: {v}"));
+            .for_each(|(i, v)| println!("{i:2}: {}", v.format_in(self.formatting)));
+    }
+
+    /// Keeps the two's complement view when the new radix can show it
+    fn set_radix(&mut self, radix: u32) {
+        let format =
+            RadixFormat::new(radix).and_then(|format| match self.formatting.radix.word_size() {
+                Some(word_size) if radix.is_power_of_two() => format.with_word_size(word_size),
+                _ => Ok(format),
+            });
+        match format {
+            Ok(format) => self.formatting.radix = format,
+            Err(e) => println!("{e}"),
+        }
+    }
+
+    fn set_word_size(&mut self, word_size: Option<u32>) {
+        let format = match word_size {
+            Some(word_size) => self.formatting.radix.with_word_size(word_size),
+            None => Ok(self.formatting.radix.without_word_size()),
+        };
+        match format {
+            Ok(format) => self.formatting.radix = format,
+            Err(e) => println!("{e}"),
+        }
+    }
+
+    fn evaluate(&mut self, f: impl FnOnce(OpStack<S<N>>) -> Result<OpStack<S<N>>, OperationError>) {
+        match self.stack.evaluate_in(self.context, f) {
+            Ok(new_stack) => self.stack = new_stack,
+            Err(e) => println!("{e}"),
+        }
     }
 
-    fn evaluate(&mut self, f: fn(OpStack<S>) -> Result<OpStack<S>, OperationError>) {
-        match self.stack.evaluate(f) {
+    fn statistics(&mut self, command: StatisticsCommand<N>) {
+        let statistics = &mut self.statistics;
+        let result = match command {
+            StatisticsCommand::Update(update) => self
+                .stack
+                .evaluate_in(self.context, |stack| update(statistics, stack)),
+            StatisticsCommand::Clear => {
+                *statistics = Statistics::default();
+                return;
+            }
+            StatisticsCommand::Show => {
+                self.print_statistics();
+                return;
+            }
+            StatisticsCommand::Push(statistic) => self.stack.evaluate_in(self.context, |stack| {
+                stack.hold(statistic(statistics)?).push()
+            }),
+            StatisticsCommand::Predict => self.stack.evaluate_in(self.context, |stack| {
+                stack.pop()?.map(|x| statistics.predict(x))?.push()
+            }),
+        };
+        match result {
             Ok(new_stack) => self.stack = new_stack,
             Err(e) => println!("{e}"),
         }
     }
 
+    fn print_statistics(&self) {
+        let statistics = &self.statistics;
+        let registers = [
+            ("n", statistics.count()),
+            ("Σx", statistics.sum_x()),
+            ("Σx²", statistics.sum_x2()),
+            ("Σy", statistics.sum_y()),
+            ("Σy²", statistics.sum_y2()),
+            ("Σxy", statistics.sum_xy()),
+        ];
+        for (name, value) in registers {
+            println!("{name:>3}: {}", value.format_in(self.formatting));
+        }
+    }
+
+    fn register(&mut self, command: RegisterCommand<N>, name: &str) {
+        let registers = &mut self.registers;
+        let result = self.stack.evaluate_in(self.context, |stack| match command {
+            RegisterCommand::Store => registers.store(name, stack),
+            RegisterCommand::StoreWith(f) => registers.store_with(name, stack, f),
+            RegisterCommand::Recall => registers.recall(name, stack),
+        });
+        match result {
+            Ok(new_stack) => self.stack = new_stack,
+            Err(e) => println!("{e}"),
+        }
+    }
+
+    fn print_registers(&self) {
+        for (name, value) in self.registers.iter() {
+            println!("{name}: {}", value.format_in(self.formatting));
+        }
+    }
+
     fn push(&mut self, n: N) {
         match self.stack.push(n) {
             Ok(()) => {}
@@ -83,3 +847,35 @@ impl CliEnvironment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn execute_runs_commands_after_numbers_before_units() {
+        let mut environment = CliEnvironment::<Quantity<f64>>::default();
+        environment.execute("5 3 min");
+        let items: Vec<_> = environment.stack.iter().map(|n| n.to_string()).collect();
+        assert_eq!(items, ["3"]);
+        environment.execute("2 km/h");
+        let items: Vec<_> = environment.stack.iter().map(|n| n.to_string()).collect();
+        assert_eq!(items, ["3", "2 km/h"]);
+    }
+
+    #[test]
+    fn execute_parses_integers_with_si_prefixes() {
+        let mut environment = CliEnvironment::<i32>::default();
+        environment.execute("3k 5m");
+        let items: Vec<_> = environment.stack.iter().map(|n| n.to_string()).collect();
+        assert_eq!(items, ["3000"]);
+    }
+
+    #[test]
+    fn execute_parses_coordinates_written_with_spaces() {
+        let mut environment = CliEnvironment::<Coordinate<f64>>::default();
+        environment.execute("(1, 2) (3,4) +");
+        let items: Vec<_> = environment.stack.iter().map(|n| n.to_string()).collect();
+        assert_eq!(items, ["(4, 6)"]);
+    }
+}
@@ -2,4 +2,7 @@
 #![cfg_attr(test, feature(assert_matches))]
 pub mod operation;
 pub mod number;
+pub mod radix;
+pub mod register;
 pub mod stack;
+pub mod statistics;
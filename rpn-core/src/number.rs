@@ -1,35 +1,284 @@
+mod angle;
+mod complex;
+mod convert;
+mod coordinate;
+mod decimal;
 mod float;
 mod integer;
+mod interval;
+mod modular;
+mod quantity;
+mod rational;
+mod saturating;
+mod unit;
+mod value;
+mod vector;
+mod wrapping;
 
-use core::error::Error;
-use core::fmt::{Debug, Display, Formatter};
 use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Arguments, Debug, Display, Formatter, Write};
 
-pub trait Number: Sized + Copy + Clone + Debug + PartialOrd {
+pub use angle::{AngleMode, ParseAngleModeError};
+pub use complex::{Complex, ParseComplexError};
+pub use convert::RoundingMode;
+pub use coordinate::{Coordinate, ParseCoordinateError};
+pub use decimal::{Decimal, HalfEven, HalfUp, ParseDecimalError, Rounding, Truncate};
+pub use interval::{Endpoint, Interval, ParseIntervalError};
+pub use modular::{Modular, ParseModularError};
+pub use quantity::{ParseQuantityError, Quantity};
+pub use rational::{DecimalExpansion, ParseRationalError, Rational};
+pub use saturating::Saturating;
+pub use unit::{Dimension, ParseUnitError, UNITS, Unit, UnitDefinition};
+pub use value::{ParseValueError, Value};
+pub use vector::{Vec2, Vec3};
+pub use wrapping::Wrapping;
+
+pub trait Number: Sized + Clone + Debug + PartialOrd {
     const ZERO: Self;
     const ONE: Self;
     const TEN: Self;
-    const MIN: Self;
-    const MAX: Self;
 
     fn add(self, other: Self) -> Result<Self, OperationError>;
     fn subtract(self, other: Self) -> Result<Self, OperationError>;
     fn multiply(self, other: Self) -> Result<Self, OperationError>;
     fn divide(self, other: Self) -> Result<Self, OperationError>;
     fn remainder(self, other: Self) -> Result<Self, OperationError>;
+
+    fn negate(self) -> Result<Self, OperationError> {
+        Self::ZERO.subtract(self)
+    }
+
+    /// Negates numbers below zero, leaving others untouched
+    fn abs(self) -> Result<Self, OperationError> {
+        if self < Self::ZERO {
+            self.negate()
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+/// Number with a finite range of representable values
+pub trait Bounded: Number {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+/// Number that can be raised to powers and have roots taken
+pub trait Power: Number {
+    /// Raises to the power of `exponent`, erring when the result is not a number of this type
+    fn power(self, exponent: Self) -> Result<Self, OperationError>;
+
+    /// Number which raised to the power of `n` gives this one, rounded toward zero for integers
+    fn nth_root(self, n: Self) -> Result<Self, OperationError>;
+
+    fn sqrt(self) -> Result<Self, OperationError> {
+        self.nth_root(Self::ONE.add(Self::ONE)?)
+    }
+}
+
+/// Fixed-width integer in two's complement, giving access to its bits; shift and rotation
+/// amounts must be between zero and the bit width, excluded
+pub trait Integer: Bounded {
+    fn and(self, other: Self) -> Self;
+    fn or(self, other: Self) -> Self;
+    fn xor(self, other: Self) -> Self;
+    fn not(self) -> Self;
+
+    fn shift_left(self, amount: Self) -> Result<Self, OperationError>;
+    /// Shifts right filling with zeros, whatever the sign
+    fn shift_right(self, amount: Self) -> Result<Self, OperationError>;
+    /// Shifts right filling with copies of the highest bit, even for unsigned integers
+    fn arithmetic_shift_right(self, amount: Self) -> Result<Self, OperationError>;
+    fn rotate_bits_left(self, amount: Self) -> Result<Self, OperationError>;
+    fn rotate_bits_right(self, amount: Self) -> Result<Self, OperationError>;
+
+    /// Number of bits set to one
+    fn count_ones(self) -> Self;
+    fn leading_zeros(self) -> Self;
+    fn trailing_zeros(self) -> Self;
+}
+
+/// Floating point number providing the real functions that cannot be derived from arithmetic,
+/// angles being in radians; functions err rather than return NaN outside of their domain
+pub trait Float: Number {
+    const PI: Self;
+
+    /// Length of the hypotenuse of a right triangle with sides `self` and `other`
+    fn hypot(self, other: Self) -> Self;
+    /// Angle of the point (`other`, `self`) from the positive x-axis, in radians
+    fn atan2(self, other: Self) -> Self;
+
+    fn sin(self) -> Result<Self, OperationError>;
+    fn cos(self) -> Result<Self, OperationError>;
+    fn tan(self) -> Result<Self, OperationError>;
+    fn asin(self) -> Result<Self, OperationError>;
+    fn acos(self) -> Result<Self, OperationError>;
+    fn atan(self) -> Result<Self, OperationError>;
+    fn sinh(self) -> Result<Self, OperationError>;
+    fn cosh(self) -> Result<Self, OperationError>;
+    fn tanh(self) -> Result<Self, OperationError>;
+    fn asinh(self) -> Result<Self, OperationError>;
+    fn acosh(self) -> Result<Self, OperationError>;
+    fn atanh(self) -> Result<Self, OperationError>;
+    fn exp(self) -> Result<Self, OperationError>;
+    /// Natural logarithm
+    fn ln(self) -> Result<Self, OperationError>;
+    fn log10(self) -> Result<Self, OperationError>;
+    fn log2(self) -> Result<Self, OperationError>;
+}
+
+/// Conversion into another number type, erring rather than changing the value, such as when
+/// narrowing an integer that does not fit or converting a float with a fractional part
+pub trait NumberConvert<T>: Sized {
+    fn convert(self) -> Result<T, OperationError>;
+}
+
+/// Conversion into an integer type, rounding away any fractional part
+pub trait RoundingConvert<T>: Sized {
+    fn convert_rounding(self, rounding: RoundingMode) -> Result<T, OperationError>;
+}
+
+/// Conversion into a fraction whose denominator is at most `max_denominator`, the last
+/// convergent of the continued fraction of the number within that bound
+pub trait ApproximateConvert<T>: Sized {
+    fn convert_approximate(self, max_denominator: T) -> Result<Rational<T>, OperationError>;
+}
+
+/// Builds a number out of an integer using only arithmetic, one decimal digit at a time
+pub(crate) fn integer<N: Number>(n: u128) -> Result<N, OperationError> {
+    let mut digits = [0u8; 39];
+    let (mut n, mut len) = (n, 0);
+    while n > 0 || len == 0 {
+        digits[len] = (n % 10) as u8;
+        (n, len) = (n / 10, len + 1);
+    }
+    digits[..len]
+        .iter()
+        .rev()
+        .try_fold(N::ZERO, |value, &digit| {
+            let value = value.multiply(N::TEN)?;
+            (0..digit).try_fold(value, |value, _| value.add(N::ONE))
+        })
+}
+
+/// Error of an operation whose exact result is out of the range of its type, on the side it is
+pub(crate) fn out_of_range(below: bool, operation: Arguments<'_>) -> OperationError {
+    let operands = Operands::new(operation);
+    match below {
+        false => NumberError::Overflow(operands).into(),
+        true => NumberError::Underflow(operands).into(),
+    }
+}
+
+/// Orders two numbers, erring when they are unordered, either because one of them is not a
+/// number or because the type has no order between them, such as complex numbers
+pub(crate) fn ordering<N: Number>(
+    a: &N,
+    b: &N,
+    operation: Arguments<'_>,
+) -> Result<Ordering, OperationError> {
+    a.partial_cmp(b).ok_or_else(|| {
+        let operands = Operands::new(operation);
+        #[allow(clippy::eq_op)]
+        match a != a || b != b {
+            true => NumberError::NotANumber(operands).into(),
+            false => NumberError::DomainError(operands).into(),
+        }
+    })
+}
+
+/// Most bytes of operand text kept along with a number error
+const OPERANDS_LENGTH: usize = 64;
+
+/// Text of the operation a number error occurred in, such as `2147483647 + 1`,
+/// cut short when longer than [`OPERANDS_LENGTH`] bytes
+#[derive(Clone, Copy)]
+pub struct Operands {
+    buffer: [u8; OPERANDS_LENGTH],
+    len: u8,
+}
+
+impl Operands {
+    pub const NONE: Self = Self {
+        buffer: [0; OPERANDS_LENGTH],
+        len: 0,
+    };
+
+    pub fn new(operation: Arguments<'_>) -> Self {
+        let mut operands = Self::NONE;
+        // Text that does not fit is left out
+        let _ = operands.write_fmt(operation);
+        operands
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buffer[..usize::from(self.len)]).unwrap_or_default()
+    }
+}
+
+impl Write for Operands {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let start = usize::from(self.len);
+        let mut end = s.len().min(OPERANDS_LENGTH - start);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.buffer[start..start + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end as u8;
+        match end == s.len() {
+            true => Ok(()),
+            false => Err(core::fmt::Error),
+        }
+    }
+}
+
+impl Debug for Operands {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Operands {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Debug)]
 pub enum NumberError {
-    Unchecked,
     DivisionByZero,
+    /// The result is above the largest value the type can hold, or too large for a float
+    Overflow(Operands),
+    /// The result is below the smallest value the type can hold,
+    /// or too close to zero for a float to tell apart from it
+    Underflow(Operands),
+    /// A float operation had no meaningful result, such as `0 * inf`
+    NotANumber(Operands),
+    /// The operand is outside of the domain of the function, such as the square root of -1
+    DomainError(Operands),
+    /// The exact result cannot be represented by the type, such as `2 ^ -1` for integers
+    InexactResult(Operands),
+    /// The divisor has no multiplicative inverse, such as 4 modulo 12
+    NotInvertible(Operands),
 }
 
 impl Display for NumberError {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match *self {
-            NumberError::Unchecked => f.write_str("Unchecked number error"),
-            NumberError::DivisionByZero => f.write_str("Division by zero error"),
+        let (message, operands) = match *self {
+            NumberError::DivisionByZero => ("Division by zero error", Operands::NONE),
+            NumberError::Overflow(operands) => ("Overflow error", operands),
+            NumberError::Underflow(operands) => ("Underflow error", operands),
+            NumberError::NotANumber(operands) => ("Not a number error", operands),
+            NumberError::DomainError(operands) => ("Domain error", operands),
+            NumberError::InexactResult(operands) => ("Inexact result error", operands),
+            NumberError::NotInvertible(operands) => ("Not invertible error", operands),
+        };
+        match operands.as_str() {
+            "" => f.write_str(message),
+            operands => f.write_fmt(format_args!("{message} in {operands}")),
         }
     }
 }
@@ -0,0 +1,92 @@
+use crate::number::{Float, integer};
+use crate::operation::OperationError;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Unit angles are entered and displayed in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AngleMode {
+    Degrees,
+    #[default]
+    Radians,
+    Gradians,
+}
+
+impl AngleMode {
+    /// Size of half a turn in this unit
+    fn half_turn<F: Float>(self) -> Result<F, OperationError> {
+        match self {
+            AngleMode::Degrees => integer(180),
+            AngleMode::Radians => Ok(F::PI),
+            AngleMode::Gradians => integer(200),
+        }
+    }
+
+    pub fn to_radians<F: Float>(self, angle: F) -> Result<F, OperationError> {
+        match self {
+            AngleMode::Radians => Ok(angle),
+            mode => angle.multiply(F::PI)?.divide(mode.half_turn()?),
+        }
+    }
+
+    pub fn from_radians<F: Float>(self, angle: F) -> Result<F, OperationError> {
+        match self {
+            AngleMode::Radians => Ok(angle),
+            mode => angle.multiply(mode.half_turn()?)?.divide(F::PI),
+        }
+    }
+}
+
+impl Display for AngleMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            AngleMode::Degrees => f.write_str("DEG"),
+            AngleMode::Radians => f.write_str("RAD"),
+            AngleMode::Gradians => f.write_str("GRAD"),
+        }
+    }
+}
+
+impl FromStr for AngleMode {
+    type Err = ParseAngleModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "deg" | "DEG" => Ok(AngleMode::Degrees),
+            "rad" | "RAD" => Ok(AngleMode::Radians),
+            "grad" | "GRAD" => Ok(AngleMode::Gradians),
+            _ => Err(ParseAngleModeError),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseAngleModeError;
+
+impl Display for ParseAngleModeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Unknown angle mode")
+    }
+}
+
+impl Error for ParseAngleModeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_to_and_from_radians() {
+        let radians = AngleMode::Degrees.to_radians(180.).unwrap();
+        assert_eq!(radians, core::f64::consts::PI);
+        assert_eq!(AngleMode::Gradians.from_radians(radians).unwrap(), 200.);
+        assert_eq!(AngleMode::Radians.to_radians(1.).unwrap(), 1.);
+    }
+
+    #[test]
+    fn parses_mode_names() {
+        assert_eq!("deg".parse::<AngleMode>().unwrap(), AngleMode::Degrees);
+        assert!("turns".parse::<AngleMode>().is_err());
+    }
+}
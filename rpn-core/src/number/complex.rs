@@ -0,0 +1,245 @@
+use crate::number::{Float, Number, NumberError};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Complex number with real and imaginary parts of any number type
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex<N> {
+    real: N,
+    imaginary: N,
+}
+
+impl<N: Number> Complex<N> {
+    pub const fn new(real: N, imaginary: N) -> Self {
+        Self { real, imaginary }
+    }
+
+    pub fn real(&self) -> &N {
+        &self.real
+    }
+
+    pub fn imaginary(&self) -> &N {
+        &self.imaginary
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.imaginary == N::ZERO
+    }
+
+    pub fn conjugate(self) -> Result<Self, OperationError> {
+        Ok(Self::new(self.real, N::ZERO.subtract(self.imaginary)?))
+    }
+
+    /// Square of the modulus, which only requires arithmetic
+    fn norm(&self) -> Result<N, OperationError> {
+        self.real
+            .clone()
+            .multiply(self.real.clone())?
+            .add(self.imaginary.clone().multiply(self.imaginary.clone())?)
+    }
+}
+
+impl<F: Float> Complex<F> {
+    /// Distance from the origin
+    pub fn modulus(self) -> F {
+        self.real.hypot(self.imaginary)
+    }
+
+    /// Angle from the positive real axis, in radians
+    pub fn argument(self) -> F {
+        self.imaginary.atan2(self.real)
+    }
+}
+
+impl<N: Number> From<N> for Complex<N> {
+    fn from(value: N) -> Self {
+        Self::new(value, N::ZERO)
+    }
+}
+
+impl<N: Number> Number for Complex<N> {
+    const ZERO: Self = Self::new(N::ZERO, N::ZERO);
+    const ONE: Self = Self::new(N::ONE, N::ZERO);
+    const TEN: Self = Self::new(N::TEN, N::ZERO);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        Ok(Self::new(
+            self.real.add(other.real)?,
+            self.imaginary.add(other.imaginary)?,
+        ))
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        Ok(Self::new(
+            self.real.subtract(other.real)?,
+            self.imaginary.subtract(other.imaginary)?,
+        ))
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        let (a, b, c, d) = (self.real, self.imaginary, other.real, other.imaginary);
+        Ok(Self::new(
+            a.clone()
+                .multiply(c.clone())?
+                .subtract(b.clone().multiply(d.clone())?)?,
+            a.multiply(d)?.add(b.multiply(c)?)?,
+        ))
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        let norm = other.norm()?;
+        if norm == N::ZERO {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        let numerator = self.multiply(other.conjugate()?)?;
+        Ok(Self::new(
+            numerator.real.divide(norm.clone())?,
+            numerator.imaginary.divide(norm)?,
+        ))
+    }
+
+    /// Whatever is left over by `divide`, so that `a == b * (a / b) + a % b`;
+    /// this is the Gaussian integer remainder for integer parts and rounding noise for floats
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        let quotient = self.clone().divide(other.clone())?;
+        self.subtract(other.multiply(quotient)?)
+    }
+
+    /// Only defined on the real line, the modulus being its extension to the complex plane
+    fn abs(self) -> Result<Self, OperationError> {
+        if !self.is_real() {
+            return Err(OperationError::InvalidOperand);
+        }
+        Ok(Self::new(self.real.abs()?, self.imaginary))
+    }
+}
+
+impl<N: Number> PartialOrd for Complex<N> {
+    /// Complex numbers are only ordered along the real line
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.is_real() && other.is_real() {
+            self.real.partial_cmp(&other.real)
+        } else {
+            None
+        }
+    }
+}
+
+impl<N: Display + Number> Display for Complex<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (self.real == N::ZERO, self.is_real()) {
+            (_, true) => f.write_fmt(format_args!("{}", self.real)),
+            (true, false) => f.write_fmt(format_args!("{}i", self.imaginary)),
+            (false, false) if self.imaginary < N::ZERO => {
+                f.write_fmt(format_args!("{}{}i", self.real, self.imaginary))
+            }
+            (false, false) => f.write_fmt(format_args!("{}+{}i", self.real, self.imaginary)),
+        }
+    }
+}
+
+impl<N: FromStr + Number> FromStr for Complex<N> {
+    type Err = ParseComplexError;
+
+    /// Parses `a`, `bi` or `a+bi` where the imaginary coefficient may be omitted as in `1-i`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(body) = s.strip_suffix('i') else {
+            let real = s.parse().map_err(|_| ParseComplexError::Real)?;
+            return Ok(Self::new(real, N::ZERO));
+        };
+        let split = body
+            .char_indices()
+            .skip(1)
+            .filter(|&(i, c)| {
+                (c == '+' || c == '-') && !body[..i].ends_with(['e', 'E'])
+            })
+            .map(|(i, _)| i)
+            .last();
+        let (real, imaginary) = match split {
+            Some(i) => (
+                body[..i].parse().map_err(|_| ParseComplexError::Real)?,
+                &body[i..],
+            ),
+            None => (N::ZERO, body),
+        };
+        let imaginary = match imaginary {
+            "" | "+" => N::ONE,
+            "-" => N::ZERO
+                .subtract(N::ONE)
+                .map_err(|_| ParseComplexError::Imaginary)?,
+            coefficient => coefficient
+                .parse()
+                .map_err(|_| ParseComplexError::Imaginary)?,
+        };
+        Ok(Self::new(real, imaginary))
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseComplexError {
+    Real,
+    Imaginary,
+}
+
+impl Display for ParseComplexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseComplexError::Real => f.write_str("Invalid real part"),
+            ParseComplexError::Imaginary => f.write_str("Invalid imaginary part"),
+        }
+    }
+}
+
+impl Error for ParseComplexError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn multiply_follows_i_squared_is_minus_one() {
+        let value = Complex::new(3, 4).multiply(Complex::new(3, -4)).unwrap();
+        assert_eq!(value, Complex::new(25, 0));
+    }
+
+    #[test]
+    fn divide_errs_on_division_by_zero() {
+        let result = Complex::new(1., 1.).divide(Complex::ZERO);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn modulus_and_argument_are_polar_coordinates() {
+        let value = Complex::new(0., 2.);
+        assert_eq!(value.modulus(), 2.);
+        assert_eq!(value.argument(), core::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn parses_cartesian_literals() {
+        assert_eq!("3+4i".parse::<Complex<i32>>().unwrap(), Complex::new(3, 4));
+        assert_eq!("-1.5e-3-i".parse::<Complex<f64>>().unwrap(), Complex::new(-1.5e-3, -1.));
+        assert_eq!("2i".parse::<Complex<i32>>().unwrap(), Complex::new(0, 2));
+        assert_eq!("7".parse::<Complex<i32>>().unwrap(), Complex::new(7, 0));
+        assert_matches!("3+xi".parse::<Complex<i32>>(), Err(ParseComplexError::Imaginary));
+    }
+
+    #[test]
+    fn displays_cartesian_form() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!(Complex::new(3, -4).to_string(), "3-4i");
+        assert_eq!(Complex::new(3, 4).to_string(), "3+4i");
+        assert_eq!(Complex::new(0, 4).to_string(), "4i");
+        assert_eq!(Complex::new(3, 0).to_string(), "3");
+    }
+}
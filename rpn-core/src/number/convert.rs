@@ -0,0 +1,298 @@
+use crate::number::{
+    ApproximateConvert, Number, NumberConvert, NumberError, Operands, Rational, RoundingConvert,
+    out_of_range,
+};
+use crate::operation::OperationError;
+use core::fmt::{Arguments, Display, Formatter};
+use libm::Libm;
+
+/// Way of rounding a number with a fractional part to an integer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceil,
+    /// To the nearest integer, halfway cases away from zero
+    Round,
+    /// Toward zero
+    Trunc,
+}
+
+impl RoundingMode {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            RoundingMode::Floor => Libm::<f64>::floor(value),
+            RoundingMode::Ceil => Libm::<f64>::ceil(value),
+            RoundingMode::Round => Libm::<f64>::round(value),
+            RoundingMode::Trunc => Libm::<f64>::trunc(value),
+        }
+    }
+}
+
+impl Display for RoundingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            RoundingMode::Floor => f.write_str("floor"),
+            RoundingMode::Ceil => f.write_str("ceil"),
+            RoundingMode::Round => f.write_str("round"),
+            RoundingMode::Trunc => f.write_str("trunc"),
+        }
+    }
+}
+
+/// Rounds a float, erring unless the result is within the range of an integer type of `bits`
+/// bits, which is checked against powers of two as those are the bounds floats hold exactly
+fn rounded(
+    value: f64,
+    rounding: RoundingMode,
+    bits: u32,
+    signed: bool,
+    operation: Arguments<'_>,
+) -> Result<f64, OperationError> {
+    if value.is_nan() {
+        return Err(NumberError::NotANumber(Operands::new(operation)).into());
+    }
+    let value = rounding.apply(value);
+    let end = 2. * (1u128 << (bits - u32::from(signed) - 1)) as f64;
+    let start = if signed { -end } else { 0. };
+    if value < start || value >= end {
+        return Err(out_of_range(value < start, operation));
+    }
+    Ok(value)
+}
+
+macro_rules! impl_convert_for_integer {
+    ($From:ty => $($To:ty),*) => {
+        $(
+            impl NumberConvert<$To> for $From {
+                fn convert(self) -> Result<$To, OperationError> {
+                    <$To>::try_from(self).map_err(|_| {
+                        let operation = format_args!("{self} as {}", stringify!($To));
+                        out_of_range(self < Self::ZERO, operation)
+                    })
+                }
+            }
+        )*
+
+        impl_convert_for_integer!($From, f32);
+        impl_convert_for_integer!($From, f64);
+    };
+    ($From:ty, $F:ty) => {
+        /// Integers too large for the float are rounded to the nearest float, unless they
+        /// overflow it
+        impl NumberConvert<$F> for $From {
+            fn convert(self) -> Result<$F, OperationError> {
+                let value = self as $F;
+                if value.is_infinite() {
+                    let operation = format_args!("{self} as {}", stringify!($F));
+                    return Err(out_of_range(false, operation));
+                }
+                Ok(value)
+            }
+        }
+
+        impl RoundingConvert<$From> for $F {
+            fn convert_rounding(self, rounding: RoundingMode) -> Result<$From, OperationError> {
+                let operation = format_args!("{rounding}({self}) as {}", stringify!($From));
+                let signed = <$From>::MIN != 0;
+                rounded(f64::from(self), rounding, <$From>::BITS, signed, operation)
+                    .map(|value| value as $From)
+            }
+        }
+
+        /// Errs on floats with a fractional part
+        impl NumberConvert<$From> for $F {
+            fn convert(self) -> Result<$From, OperationError> {
+                let operation = format_args!("{self} as {}", stringify!($From));
+                let signed = <$From>::MIN != 0;
+                let value = f64::from(self);
+                let rounded = rounded(value, RoundingMode::Trunc, <$From>::BITS, signed, operation)?;
+                if rounded != value {
+                    return Err(NumberError::InexactResult(Operands::new(operation)).into());
+                }
+                Ok(rounded as $From)
+            }
+        }
+    };
+}
+
+impl_convert_for_integer!(i8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(i16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(i32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(i64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(i128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(u8 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(u16 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(u32 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(u64 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_convert_for_integer!(u128 => i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl NumberConvert<f64> for f64 {
+    fn convert(self) -> Result<f64, OperationError> {
+        Ok(self)
+    }
+}
+
+impl NumberConvert<f32> for f32 {
+    fn convert(self) -> Result<f32, OperationError> {
+        Ok(self)
+    }
+}
+
+impl NumberConvert<f64> for f32 {
+    fn convert(self) -> Result<f64, OperationError> {
+        Ok(f64::from(self))
+    }
+}
+
+/// Floats are rounded to the nearest `f32`, erring when they overflow or vanish
+impl NumberConvert<f32> for f64 {
+    fn convert(self) -> Result<f32, OperationError> {
+        let value = self as f32;
+        let operation = format_args!("{self} as f32");
+        if value.is_infinite() && self.is_finite() {
+            return Err(out_of_range(false, operation));
+        }
+        if value == 0. && self != 0. {
+            return Err(NumberError::Underflow(Operands::new(operation)).into());
+        }
+        Ok(value)
+    }
+}
+
+macro_rules! impl_approximate_convert_for_float {
+    ($F:ty) => {
+        /// Continued fractions of floats end once their remaining part is zero, or once the
+        /// next convergent no longer fits the type or the maximum denominator
+        impl<I: Number> ApproximateConvert<I> for $F
+        where
+            $F: RoundingConvert<I>,
+        {
+            fn convert_approximate(
+                self,
+                max_denominator: I,
+            ) -> Result<Rational<I>, OperationError> {
+                if max_denominator < I::ONE {
+                    let operation = format_args!("{self} as fraction of {max_denominator:?}");
+                    return Err(NumberError::DomainError(Operands::new(operation)).into());
+                }
+                let value = Libm::<$F>::fabs(self);
+                let whole = value.convert_rounding(RoundingMode::Floor)?;
+                let (mut numerators, mut denominators) = ((I::ONE, whole), (I::ZERO, I::ONE));
+                let mut rest = value - Libm::<$F>::floor(value);
+                while rest != 0. {
+                    rest = 1. / rest;
+                    let next = |term: I, (previous, last): (I, I)| {
+                        term.multiply(last.clone())?
+                            .add(previous)
+                            .map(|next| (last, next))
+                    };
+                    let Ok(term) = rest.convert_rounding(RoundingMode::Floor) else {
+                        break;
+                    };
+                    let (Ok(numerator), Ok(denominator)) = (
+                        next(term.clone(), numerators.clone()),
+                        next(term, denominators.clone()),
+                    ) else {
+                        break;
+                    };
+                    if denominator.1 > max_denominator {
+                        break;
+                    }
+                    (numerators, denominators) = (numerator, denominator);
+                    rest = rest - Libm::<$F>::floor(rest);
+                }
+                let numerator = match self < 0. {
+                    true => numerators.1.negate()?,
+                    false => numerators.1,
+                };
+                Rational::new(numerator, denominators.1)
+            }
+        }
+    };
+}
+
+impl_approximate_convert_for_float!(f32);
+impl_approximate_convert_for_float!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn narrowing_errs_rather_than_truncating() {
+        assert_matches!(NumberConvert::<i8>::convert(100i32), Ok(100));
+        assert_matches!(
+            NumberConvert::<i8>::convert(200i32),
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+        assert_matches!(
+            NumberConvert::<u32>::convert(-1i64),
+            Err(OperationError::Number(NumberError::Underflow(_)))
+        );
+        assert_matches!(
+            NumberConvert::<f32>::convert(u128::MAX),
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn float_to_integer_rounds_in_every_mode() {
+        let round =
+            |value: f64, rounding| RoundingConvert::<i32>::convert_rounding(value, rounding);
+        assert_matches!(round(-2.5, RoundingMode::Floor), Ok(-3));
+        assert_matches!(round(-2.5, RoundingMode::Ceil), Ok(-2));
+        assert_matches!(round(-2.5, RoundingMode::Round), Ok(-3));
+        assert_matches!(round(-2.5, RoundingMode::Trunc), Ok(-2));
+        assert_matches!(round(2147483647.4, RoundingMode::Round), Ok(i32::MAX));
+        assert_matches!(
+            round(2147483647.5, RoundingMode::Round),
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+        assert_matches!(
+            round(f64::NAN, RoundingMode::Floor),
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+        assert_matches!(
+            RoundingConvert::<u8>::convert_rounding(-0.5f32, RoundingMode::Floor),
+            Err(OperationError::Number(NumberError::Underflow(_)))
+        );
+    }
+
+    #[test]
+    fn float_to_integer_errs_on_fractional_part() {
+        assert_matches!(NumberConvert::<i64>::convert(-3f64), Ok(-3));
+        assert_matches!(
+            NumberConvert::<i64>::convert(0.5f64),
+            Err(OperationError::Number(NumberError::InexactResult(_)))
+        );
+        assert_matches!(
+            NumberConvert::<i64>::convert(9.3e18f64),
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn float_to_rational_uses_continued_fractions() {
+        let approximate = |value: f64, max: i64| {
+            value
+                .convert_approximate(max)
+                .map(|r| (*r.numerator(), *r.denominator()))
+        };
+        assert_matches!(approximate(0.1, 1000), Ok((1, 10)));
+        assert_matches!(approximate(-0.75, 1000), Ok((-3, 4)));
+        assert_matches!(approximate(core::f64::consts::PI, 100), Ok((22, 7)));
+        assert_matches!(approximate(core::f64::consts::PI, 1000), Ok((355, 113)));
+        assert_matches!(approximate(2.5, 1), Ok((2, 1)));
+        assert_matches!(
+            approximate(1.5, 0),
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+        assert_matches!(
+            approximate(f64::INFINITY, 10),
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::number::{Float, Number, Vec2, Vec3};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Stack item holding either a scalar or XY or XYZ coordinates,
+/// arithmetic between vectors being component-wise and vectors scaling by scalars
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Coordinate<N> {
+    Scalar(N),
+    Vec2(Vec2<N>),
+    Vec3(Vec3<N>),
+}
+
+impl<N: Number> Coordinate<N> {
+    pub fn scalar(self) -> Result<N, OperationError> {
+        match self {
+            Coordinate::Scalar(n) => Ok(n),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    /// Multiplies a vector by a scalar, in either order
+    pub fn scale(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Scalar(s), Coordinate::Vec2(v))
+            | (Coordinate::Vec2(v), Coordinate::Scalar(s)) => Ok(Coordinate::Vec2(v.scale(s)?)),
+            (Coordinate::Scalar(s), Coordinate::Vec3(v))
+            | (Coordinate::Vec3(v), Coordinate::Scalar(s)) => Ok(Coordinate::Vec3(v.scale(s)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    pub fn dot(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Vec2(a), Coordinate::Vec2(b)) => Ok(Coordinate::Scalar(a.dot(b)?)),
+            (Coordinate::Vec3(a), Coordinate::Vec3(b)) => Ok(Coordinate::Scalar(a.dot(b)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    pub fn cross(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Vec3(a), Coordinate::Vec3(b)) => Ok(Coordinate::Vec3(a.cross(b)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    /// Scalar components of a vector, in order
+    pub fn components(self) -> Result<impl Iterator<Item = Self>, OperationError> {
+        let components = match self {
+            Coordinate::Scalar(_) => return Err(OperationError::InvalidOperand),
+            Coordinate::Vec2(Vec2 { x, y }) => [Some(x), Some(y), None],
+            Coordinate::Vec3(Vec3 { x, y, z }) => [Some(x), Some(y), Some(z)],
+        };
+        Ok(components.into_iter().flatten().map(Coordinate::Scalar))
+    }
+}
+
+impl<F: Float> Coordinate<F> {
+    /// Euclidean length of a vector, or absolute value of a scalar
+    pub fn norm(self) -> F {
+        match self {
+            Coordinate::Scalar(s) => s.hypot(F::ZERO),
+            Coordinate::Vec2(v) => v.norm(),
+            Coordinate::Vec3(v) => v.norm(),
+        }
+    }
+}
+
+impl<N: Number> Default for Coordinate<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<N> From<Vec2<N>> for Coordinate<N> {
+    fn from(value: Vec2<N>) -> Self {
+        Coordinate::Vec2(value)
+    }
+}
+
+impl<N> From<Vec3<N>> for Coordinate<N> {
+    fn from(value: Vec3<N>) -> Self {
+        Coordinate::Vec3(value)
+    }
+}
+
+impl<N: Number> Number for Coordinate<N> {
+    const ZERO: Self = Coordinate::Scalar(N::ZERO);
+    const ONE: Self = Coordinate::Scalar(N::ONE);
+    const TEN: Self = Coordinate::Scalar(N::TEN);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Scalar(a), Coordinate::Scalar(b)) => Ok(Coordinate::Scalar(a.add(b)?)),
+            (Coordinate::Vec2(a), Coordinate::Vec2(b)) => Ok(Coordinate::Vec2(a.add(b)?)),
+            (Coordinate::Vec3(a), Coordinate::Vec3(b)) => Ok(Coordinate::Vec3(a.add(b)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Scalar(a), Coordinate::Scalar(b)) => {
+                Ok(Coordinate::Scalar(a.subtract(b)?))
+            }
+            (Coordinate::Vec2(a), Coordinate::Vec2(b)) => Ok(Coordinate::Vec2(a.subtract(b)?)),
+            (Coordinate::Vec3(a), Coordinate::Vec3(b)) => Ok(Coordinate::Vec3(a.subtract(b)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    /// Multiplies scalars or scales a vector, use dot or cross products to multiply two vectors
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Scalar(a), Coordinate::Scalar(b)) => {
+                Ok(Coordinate::Scalar(a.multiply(b)?))
+            }
+            (a, b) => a.scale(b),
+        }
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        match (self, other) {
+            (Coordinate::Scalar(a), Coordinate::Scalar(b)) => Ok(Coordinate::Scalar(a.divide(b)?)),
+            (Coordinate::Vec2(v), Coordinate::Scalar(s)) => Ok(Coordinate::Vec2(v.shrink(s)?)),
+            (Coordinate::Vec3(v), Coordinate::Scalar(s)) => Ok(Coordinate::Vec3(v.shrink(s)?)),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        Ok(Coordinate::Scalar(
+            self.scalar()?.remainder(other.scalar()?)?,
+        ))
+    }
+
+    /// Absolute value of a scalar, use the norm for the length of a vector
+    fn abs(self) -> Result<Self, OperationError> {
+        Ok(Coordinate::Scalar(self.scalar()?.abs()?))
+    }
+}
+
+impl<N: Number> PartialOrd for Coordinate<N> {
+    /// Only scalars are ordered, vectors are merely equal or not
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Coordinate::Scalar(a), Coordinate::Scalar(b)) => a.partial_cmp(b),
+            (a, b) if a == b => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl<N: Display> Display for Coordinate<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Coordinate::Scalar(n) => n.fmt(f),
+            Coordinate::Vec2(v) => v.fmt(f),
+            Coordinate::Vec3(v) => v.fmt(f),
+        }
+    }
+}
+
+impl<N: FromStr + Number> FromStr for Coordinate<N> {
+    type Err = ParseCoordinateError;
+
+    /// Parses a scalar, or comma separated coordinates between parentheses as in `(1,2)`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(components) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) else {
+            return s
+                .parse()
+                .map(Coordinate::Scalar)
+                .map_err(|_| ParseCoordinateError::Component);
+        };
+        let mut components = components.split(',').map(|c| c.trim().parse());
+        let mut next = || match components.next() {
+            Some(Ok(n)) => Ok(Some(n)),
+            Some(Err(_)) => Err(ParseCoordinateError::Component),
+            None => Ok(None),
+        };
+        match (next()?, next()?, next()?, next()?) {
+            (Some(x), Some(y), None, None) => Ok(Coordinate::Vec2(Vec2::new(x, y))),
+            (Some(x), Some(y), Some(z), None) => Ok(Coordinate::Vec3(Vec3::new(x, y, z))),
+            _ => Err(ParseCoordinateError::Dimension),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseCoordinateError {
+    Component,
+    Dimension,
+}
+
+impl Display for ParseCoordinateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseCoordinateError::Component => f.write_str("Invalid coordinate"),
+            ParseCoordinateError::Dimension => {
+                f.write_str("Coordinates must have 2 or 3 components")
+            }
+        }
+    }
+}
+
+impl Error for ParseCoordinateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn add_is_component_wise() {
+        let value = Coordinate::from(Vec2::new(1, 2)).add(Vec2::new(3, 4).into());
+        assert_eq!(value.unwrap(), Coordinate::Vec2(Vec2::new(4, 6)));
+    }
+
+    #[test]
+    fn add_errs_on_mismatched_dimensions() {
+        let value = Coordinate::from(Vec2::new(1, 2)).add(Vec3::new(3, 4, 5).into());
+        assert_matches!(value, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn multiply_scales_vectors() {
+        let value = Coordinate::Scalar(2).multiply(Vec3::new(1, 2, 3).into());
+        assert_eq!(value.unwrap(), Coordinate::Vec3(Vec3::new(2, 4, 6)));
+    }
+
+    #[test]
+    fn parses_coordinates() {
+        let parse = |s: &str| s.parse::<Coordinate<i32>>();
+        assert_eq!(parse("(1, 2)").unwrap(), Coordinate::Vec2(Vec2::new(1, 2)));
+        assert_eq!(
+            parse("(1,2,3)").unwrap(),
+            Coordinate::Vec3(Vec3::new(1, 2, 3))
+        );
+        assert_eq!(parse("4").unwrap(), Coordinate::Scalar(4));
+        assert_matches!(parse("(1,2,3,4)"), Err(ParseCoordinateError::Dimension));
+    }
+}
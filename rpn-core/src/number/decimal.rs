@@ -0,0 +1,325 @@
+use crate::number::{Bounded, Number, NumberError, out_of_range};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Arguments, Debug, Display, Formatter, Write};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// Fixed-point decimal number with exactly `SCALE` fractional digits, stored as a scaled `i128`;
+/// results of `multiply` and `divide` that need more digits are rounded according to `R`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Decimal<const SCALE: u32, R = HalfEven> {
+    scaled: i128,
+    rounding: PhantomData<R>,
+}
+
+/// How a quotient is brought back to the last kept digit
+pub trait Rounding: Copy + Debug + PartialEq {
+    /// Adjusts the truncated `quotient` given the `remainder` of the division by `divisor`
+    fn round(quotient: i128, remainder: i128, divisor: i128) -> i128;
+}
+
+/// Rounds ties to the even neighbour, also known as banker's rounding
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HalfEven;
+
+/// Rounds ties away from zero
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HalfUp;
+
+/// Drops extra digits, rounding toward zero
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Truncate;
+
+/// Compares the remainder with half the divisor, without overflowing
+fn compare_half(remainder: i128, divisor: i128) -> Ordering {
+    let (remainder, divisor) = (remainder.unsigned_abs(), divisor.unsigned_abs());
+    remainder.cmp(&(divisor - remainder))
+}
+
+/// Quotient moved one step away from zero
+fn away_from_zero(quotient: i128, remainder: i128, divisor: i128) -> i128 {
+    if (remainder < 0) == (divisor < 0) {
+        quotient + 1
+    } else {
+        quotient - 1
+    }
+}
+
+impl Rounding for HalfEven {
+    fn round(quotient: i128, remainder: i128, divisor: i128) -> i128 {
+        match compare_half(remainder, divisor) {
+            Ordering::Greater => away_from_zero(quotient, remainder, divisor),
+            Ordering::Equal if quotient % 2 != 0 => away_from_zero(quotient, remainder, divisor),
+            _ => quotient,
+        }
+    }
+}
+
+impl Rounding for HalfUp {
+    fn round(quotient: i128, remainder: i128, divisor: i128) -> i128 {
+        match compare_half(remainder, divisor) {
+            Ordering::Less => quotient,
+            _ => away_from_zero(quotient, remainder, divisor),
+        }
+    }
+}
+
+impl Rounding for Truncate {
+    fn round(quotient: i128, _remainder: i128, _divisor: i128) -> i128 {
+        quotient
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> Decimal<SCALE, R> {
+    const FACTOR: i128 = 10i128.pow(SCALE);
+
+    /// Creates a decimal from its value in units of the last digit, so that `new(1234)` is `12.34`
+    /// with a scale of 2
+    pub const fn new(scaled: i128) -> Self {
+        Self {
+            scaled,
+            rounding: PhantomData,
+        }
+    }
+
+    /// Value in units of the last digit
+    pub const fn scaled(&self) -> i128 {
+        self.scaled
+    }
+
+    fn divide_rounded(
+        numerator: i128,
+        divisor: i128,
+        operation: Arguments<'_>,
+    ) -> Result<i128, OperationError> {
+        let quotient = numerator
+            .checked_div(divisor)
+            .ok_or_else(|| out_of_range(false, operation))?;
+        let remainder = numerator % divisor;
+        if remainder == 0 {
+            return Ok(quotient);
+        }
+        Ok(R::round(quotient, remainder, divisor))
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> Default for Decimal<SCALE, R> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> Number for Decimal<SCALE, R> {
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(Self::FACTOR);
+    const TEN: Self = Self::new(10 * Self::FACTOR);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        let scaled = self.scaled.checked_add(other.scaled);
+        let below = other.scaled < 0;
+        Ok(Self::new(scaled.ok_or_else(|| {
+            out_of_range(below, format_args!("{self} + {other}"))
+        })?))
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        let scaled = self.scaled.checked_sub(other.scaled);
+        let below = other.scaled > 0;
+        Ok(Self::new(scaled.ok_or_else(|| {
+            out_of_range(below, format_args!("{self} - {other}"))
+        })?))
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        let below = (self.scaled < 0) != (other.scaled < 0);
+        let product = self.scaled.checked_mul(other.scaled);
+        let product =
+            product.ok_or_else(|| out_of_range(below, format_args!("{self} * {other}")))?;
+        let operation = format_args!("{self} * {other}");
+        Ok(Self::new(Self::divide_rounded(
+            product,
+            Self::FACTOR,
+            operation,
+        )?))
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        if other.scaled == 0 {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        let below = (self.scaled < 0) != (other.scaled < 0);
+        let numerator = self.scaled.checked_mul(Self::FACTOR);
+        let numerator =
+            numerator.ok_or_else(|| out_of_range(below, format_args!("{self} / {other}")))?;
+        let operation = format_args!("{self} / {other}");
+        Ok(Self::new(Self::divide_rounded(
+            numerator,
+            other.scaled,
+            operation,
+        )?))
+    }
+
+    /// Remainder of the division truncated toward zero, which is always exact;
+    /// the quotient of the smallest decimal by minus one unit overflows, but its remainder is zero
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        if other.scaled == 0 {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        Ok(Self::new(
+            self.scaled.checked_rem(other.scaled).unwrap_or(0),
+        ))
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> Bounded for Decimal<SCALE, R> {
+    const MIN: Self = Self::new(i128::MIN);
+    const MAX: Self = Self::new(i128::MAX);
+}
+
+impl<const SCALE: u32, R: Rounding> PartialOrd for Decimal<SCALE, R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.scaled.partial_cmp(&other.scaled)
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> Display for Decimal<SCALE, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let magnitude = self.scaled.unsigned_abs();
+        let factor = Self::FACTOR.unsigned_abs();
+        if self.scaled < 0 {
+            f.write_char('-')?;
+        }
+        f.write_fmt(format_args!("{}", magnitude / factor))?;
+        if SCALE > 0 {
+            let digits = SCALE as usize;
+            f.write_fmt(format_args!(".{:0digits$}", magnitude % factor))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const SCALE: u32, R: Rounding> FromStr for Decimal<SCALE, R> {
+    type Err = ParseDecimalError;
+
+    /// Parses `12`, `12.3` or `-12.34`, with at most `SCALE` fractional digits
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+        if fraction.len() > SCALE as usize {
+            return Err(ParseDecimalError::TooManyDigits);
+        }
+        let mut scaled: i128 = 0;
+        let padding = (fraction.len()..SCALE as usize).map(|_| '0');
+        for c in integer.chars().chain(fraction.chars()).chain(padding) {
+            let digit = c.to_digit(10).ok_or(ParseDecimalError::InvalidDigit)?;
+            scaled = scaled
+                .checked_mul(10)
+                .and_then(|s| s.checked_add(digit.into()))
+                .ok_or(ParseDecimalError::Overflow)?;
+        }
+        Ok(Self::new(if negative { -scaled } else { scaled }))
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseDecimalError {
+    InvalidDigit,
+    TooManyDigits,
+    Overflow,
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseDecimalError::InvalidDigit => f.write_str("Invalid digit"),
+            ParseDecimalError::TooManyDigits => f.write_str("Too many fractional digits"),
+            ParseDecimalError::Overflow => f.write_str("Decimal overflows"),
+        }
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operation::divide;
+    use crate::stack::{LargeStack, Stack};
+    use core::assert_matches::assert_matches;
+
+    type Money<R = HalfEven> = Decimal<2, R>;
+
+    fn money<R: Rounding>(s: &str) -> Money<R> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn add_is_exact() {
+        let sum = money::<HalfEven>("0.10").add(money("0.20")).unwrap();
+        assert_eq!(sum, money("0.30"));
+    }
+
+    #[test]
+    fn divide_rounds_half_to_even() {
+        let half = money::<HalfEven>("0.05").divide(money("2")).unwrap();
+        assert_eq!(half, money("0.02"));
+        let half = money::<HalfEven>("0.07").divide(money("2")).unwrap();
+        assert_eq!(half, money("0.04"));
+        let half = money::<HalfEven>("-0.07").divide(money("2")).unwrap();
+        assert_eq!(half, money("-0.04"));
+    }
+
+    #[test]
+    fn divide_rounds_half_up() {
+        let half = money::<HalfUp>("0.05").divide(money("2")).unwrap();
+        assert_eq!(half, money("0.03"));
+        let half = money::<HalfUp>("-0.05").divide(money("2")).unwrap();
+        assert_eq!(half, money("-0.03"));
+    }
+
+    #[test]
+    fn multiply_truncates() {
+        let product = money::<Truncate>("1.99").multiply(money("0.5")).unwrap();
+        assert_eq!(product, money("0.99"));
+    }
+
+    #[test]
+    fn divide_errs_on_division_by_zero() {
+        let result = Money::<HalfEven>::ONE.divide(Money::ZERO);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn parses_and_displays_fixed_digits() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!(money::<HalfEven>("12.3").to_string(), "12.30");
+        assert_eq!(money::<HalfEven>("-0.5").to_string(), "-0.50");
+        assert_eq!(money::<HalfEven>("7").to_string(), "7.00");
+        assert_matches!(
+            "1.234".parse::<Money>(),
+            Err(ParseDecimalError::TooManyDigits)
+        );
+        assert_matches!("1.x".parse::<Money>(), Err(ParseDecimalError::InvalidDigit));
+    }
+
+    #[test]
+    fn works_on_large_stack() {
+        let mut stack = LargeStack::default();
+        stack.push(money::<HalfEven>("10.00")).unwrap();
+        stack.push(money("3")).unwrap();
+        let result = stack.evaluate(divide).unwrap();
+        assert_eq!(result.iter().next(), Some(&money("3.33")));
+    }
+}
@@ -1,68 +1,153 @@
-use crate::number::{Number, NumberError};
+use crate::number::{Bounded, Float, Number, NumberError, Operands, Power};
 use crate::operation::OperationError;
+use core::fmt::Arguments;
+use libm::Libm;
+
+/// Checks the result of a float operation, which errs when it is not a number, infinite,
+/// or flushed to zero although `vanishes` says the exact result is not zero
+fn checked<T: Bounded>(
+    answer: T,
+    vanishes: bool,
+    operation: Arguments<'_>,
+) -> Result<T, OperationError> {
+    #[allow(clippy::eq_op)]
+    let error = if answer != answer {
+        NumberError::NotANumber
+    } else if answer < T::MIN || answer > T::MAX {
+        NumberError::Overflow
+    } else if vanishes && answer == T::ZERO {
+        NumberError::Underflow
+    } else {
+        return Ok(answer);
+    };
+    Err(error(Operands::new(operation)).into())
+}
+
+/// Checked function of a float, erring outside of the domain of the function or on overflow
+macro_rules! float_function {
+    ($T:ty, $name:ident, $libm:ident) => {
+        float_function!($T, $name, $libm, |_| true);
+    };
+    ($T:ty, $name:ident, $libm:ident, $domain:expr) => {
+        fn $name(self) -> Result<Self, OperationError> {
+            let in_domain: fn($T) -> bool = $domain;
+            let operation = format_args!("{}({self})", stringify!($name));
+            if self.is_nan() {
+                return Err(NumberError::NotANumber(Operands::new(operation)).into());
+            }
+            let answer = Libm::<$T>::$libm(self);
+            if !in_domain(self) || answer.is_nan() {
+                return Err(NumberError::DomainError(Operands::new(operation)).into());
+            }
+            checked(answer, false, operation)
+        }
+    };
+}
 
 macro_rules! impl_number_for_float {
-    ($T:ty) => {
+    ($T:ident) => {
         impl Number for $T {
             const ZERO: Self = 0.;
             const ONE: Self = 1.;
             const TEN: Self = 10.;
-            const MAX: Self = Self::MAX;
-            const MIN: Self = Self::MIN;
 
             fn add(self, other: Self) -> Result<Self, OperationError> {
-                let answer = self + other;
-                if answer.is_infinite() {
-                    Err(NumberError::Unchecked.into())
-                } else {
-                    Ok(answer)
-                }
+                checked(self + other, false, format_args!("{self} + {other}"))
             }
 
             fn subtract(self, other: Self) -> Result<Self, OperationError> {
-                let answer = self - other;
-                if answer.is_infinite() {
-                    Err(NumberError::Unchecked.into())
-                } else {
-                    Ok(answer)
-                }
+                checked(self - other, false, format_args!("{self} - {other}"))
             }
 
             fn multiply(self, other: Self) -> Result<Self, OperationError> {
-                let answer = self * other;
-                if answer.is_infinite() {
-                    Err(NumberError::Unchecked.into())
-                } else {
-                    Ok(answer)
-                }
+                checked(
+                    self * other,
+                    self != 0. && other != 0.,
+                    format_args!("{self} * {other}"),
+                )
             }
 
             fn divide(self, other: Self) -> Result<Self, OperationError> {
                 if other == Self::ZERO {
                     return Err(NumberError::DivisionByZero.into());
                 }
-                let answer = self / other;
-                if answer.is_infinite() {
-                    Err(NumberError::Unchecked.into())
-                } else {
-                    Ok(answer)
-                }
+                checked(self / other, self != 0., format_args!("{self} / {other}"))
             }
-            
+
             fn remainder(self, other: Self) -> Result<Self, OperationError> {
                 if other == Self::ZERO {
                     return Err(NumberError::DivisionByZero.into());
                 }
-                let answer = self % other;
-                if answer.is_infinite() {
-                    Err(NumberError::Unchecked.into())
-                } else {
-                    Ok(answer)
+                checked(self % other, false, format_args!("{self} % {other}"))
+            }
+        }
+
+        impl Bounded for $T {
+            const MAX: Self = Self::MAX;
+            const MIN: Self = Self::MIN;
+        }
+
+        impl Power for $T {
+            fn power(self, exponent: Self) -> Result<Self, OperationError> {
+                let operation = format_args!("{self} ^ {exponent}");
+                let answer = Libm::<$T>::pow(self, exponent);
+                if answer.is_nan() && !self.is_nan() && !exponent.is_nan() {
+                    return Err(NumberError::DomainError(Operands::new(operation)).into());
                 }
+                checked(answer, self != 0., operation)
             }
+
+            /// Odd roots of negative numbers are negative, other roots of them are undefined
+            fn nth_root(self, n: Self) -> Result<Self, OperationError> {
+                if n == Self::ZERO {
+                    let operands = Operands::new(format_args!("root({self}, {n})"));
+                    return Err(NumberError::DomainError(operands).into());
+                }
+                if self < Self::ZERO && (n % 2. == 1. || n % 2. == -1.) {
+                    return Ok(-(-self).power(1. / n)?);
+                }
+                self.power(1. / n)
+            }
+
+            fn sqrt(self) -> Result<Self, OperationError> {
+                if self < Self::ZERO {
+                    let operands = Operands::new(format_args!("sqrt({self})"));
+                    return Err(NumberError::DomainError(operands).into());
+                }
+                checked(Libm::<$T>::sqrt(self), false, format_args!("sqrt({self})"))
+            }
+        }
+
+        impl Float for $T {
+            const PI: Self = core::$T::consts::PI;
+
+            fn hypot(self, other: Self) -> Self {
+                Libm::<$T>::hypot(self, other)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                Libm::<$T>::atan2(self, other)
+            }
+
+            float_function!($T, sin, sin);
+            float_function!($T, cos, cos);
+            float_function!($T, tan, tan);
+            float_function!($T, asin, asin);
+            float_function!($T, acos, acos);
+            float_function!($T, atan, atan);
+            float_function!($T, sinh, sinh);
+            float_function!($T, cosh, cosh);
+            float_function!($T, tanh, tanh);
+            float_function!($T, asinh, asinh);
+            float_function!($T, acosh, acosh);
+            float_function!($T, atanh, atanh, |x| -1. < x && x < 1.);
+            float_function!($T, exp, exp);
+            float_function!($T, ln, log, |x| x > 0.);
+            float_function!($T, log10, log10, |x| x > 0.);
+            float_function!($T, log2, log2, |x| x > 0.);
         }
-    }
+    };
 }
 
 impl_number_for_float!(f32);
-impl_number_for_float!(f64);
\ No newline at end of file
+impl_number_for_float!(f64);
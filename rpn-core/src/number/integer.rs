@@ -1,51 +1,187 @@
-use crate::number::{Number,NumberError};
+use crate::number::{Bounded, Integer, Number, NumberError, Operands, Power, out_of_range};
 use crate::operation::OperationError;
+use core::fmt::Display;
+
+/// Checks a shift or rotation amount, which must be less than the bit width
+fn shift_amount<T: Display + Copy>(
+    value: T,
+    amount: T,
+    bits: u32,
+    operator: &str,
+) -> Result<u32, OperationError>
+where
+    u32: TryFrom<T>,
+{
+    u32::try_from(amount)
+        .ok()
+        .filter(|&amount| amount < bits)
+        .ok_or_else(|| {
+            let operands = Operands::new(format_args!("{value} {operator} {amount}"));
+            NumberError::DomainError(operands).into()
+        })
+}
 
 macro_rules! impl_number_for_integer {
-    ($T:ty) => {
+    ($T:ty, $Unsigned:ty, $Signed:ty) => {
         impl Number for $T {
             const ZERO: Self = 0;
             const ONE: Self = 1;
             const TEN: Self = 10;
-            const MAX: Self = Self::MAX;
-            const MIN: Self = Self::MIN;
 
             fn add(self, other: Self) -> Result<Self, OperationError> {
-                self.checked_add(other).ok_or(NumberError::Unchecked.into())
+                self.checked_add(other).ok_or_else(|| {
+                    out_of_range(other < Self::ZERO, format_args!("{self} + {other}"))
+                })
             }
 
             fn subtract(self, other: Self) -> Result<Self, OperationError> {
-                self.checked_sub(other).ok_or(NumberError::Unchecked.into())
+                self.checked_sub(other).ok_or_else(|| {
+                    out_of_range(other > Self::ZERO, format_args!("{self} - {other}"))
+                })
             }
 
             fn multiply(self, other: Self) -> Result<Self, OperationError> {
-                self.checked_mul(other).ok_or(NumberError::Unchecked.into())
+                self.checked_mul(other).ok_or_else(|| {
+                    let below = (self < Self::ZERO) != (other < Self::ZERO);
+                    out_of_range(below, format_args!("{self} * {other}"))
+                })
             }
 
+            /// Only `MIN / -1` overflows, its result being one more than `MAX`
             fn divide(self, other: Self) -> Result<Self, OperationError> {
                 if other == Self::ZERO {
                     return Err(NumberError::DivisionByZero.into());
                 }
-                self.checked_div(other).ok_or(NumberError::Unchecked.into())
+                self.checked_div(other)
+                    .ok_or_else(|| out_of_range(false, format_args!("{self} / {other}")))
             }
 
+            /// `MIN % -1` overflows while computing the quotient, but its remainder is zero
             fn remainder(self, other: Self) -> Result<Self, OperationError> {
                 if other == Self::ZERO {
                     return Err(NumberError::DivisionByZero.into());
                 }
-                self.checked_rem(other).ok_or(NumberError::Unchecked.into())
+                Ok(self.checked_rem(other).unwrap_or(Self::ZERO))
+            }
+        }
+
+        impl Power for $T {
+            /// Negative exponents only give integers for bases of one and minus one
+            fn power(self, exponent: Self) -> Result<Self, OperationError> {
+                if exponent < Self::ZERO {
+                    let operands = Operands::new(format_args!("{self} ^ {exponent}"));
+                    return match self {
+                        0 => Err(NumberError::DivisionByZero.into()),
+                        1 => Ok(1),
+                        _ if self.checked_neg() == Some(1) => match exponent % 2 {
+                            0 => Ok(1),
+                            _ => Ok(self),
+                        },
+                        _ => Err(NumberError::InexactResult(operands).into()),
+                    };
+                }
+                let below = self < Self::ZERO && exponent % 2 == 1;
+                u32::try_from(exponent)
+                    .ok()
+                    .and_then(|exponent| self.checked_pow(exponent))
+                    .ok_or_else(|| out_of_range(below, format_args!("{self} ^ {exponent}")))
+            }
+
+            /// Searches the root between zero and the number itself
+            fn nth_root(self, n: Self) -> Result<Self, OperationError> {
+                let negative = self < Self::ZERO;
+                let domain_error =
+                    || NumberError::DomainError(Operands::new(format_args!("root({self}, {n})")));
+                let n = u32::try_from(n).map_err(|_| domain_error())?;
+                if n == 0 || (negative && n % 2 == 0) {
+                    return Err(domain_error().into());
+                }
+                let fits = |root: Self| match root.checked_pow(n) {
+                    Some(power) if negative => power >= self,
+                    Some(power) => power <= self,
+                    None => false,
+                };
+                let (mut closest, mut farthest) = (Self::ZERO, self);
+                while closest != farthest {
+                    let middle = farthest - (farthest - closest) / 2;
+                    if fits(middle) {
+                        closest = middle;
+                    } else if negative {
+                        farthest = middle + 1;
+                    } else {
+                        farthest = middle - 1;
+                    }
+                }
+                Ok(closest)
+            }
+        }
+
+        impl Bounded for $T {
+            const MAX: Self = Self::MAX;
+            const MIN: Self = Self::MIN;
+        }
+
+        impl Integer for $T {
+            fn and(self, other: Self) -> Self {
+                self & other
+            }
+
+            fn or(self, other: Self) -> Self {
+                self | other
+            }
+
+            fn xor(self, other: Self) -> Self {
+                self ^ other
+            }
+
+            fn not(self) -> Self {
+                !self
+            }
+
+            fn shift_left(self, amount: Self) -> Result<Self, OperationError> {
+                Ok(self << shift_amount(self, amount, Self::BITS, "<<")?)
+            }
+
+            fn shift_right(self, amount: Self) -> Result<Self, OperationError> {
+                let amount = shift_amount(self, amount, Self::BITS, ">>")?;
+                Ok((self as $Unsigned >> amount) as Self)
+            }
+
+            fn arithmetic_shift_right(self, amount: Self) -> Result<Self, OperationError> {
+                let amount = shift_amount(self, amount, Self::BITS, "sar")?;
+                Ok((self as $Signed >> amount) as Self)
+            }
+
+            fn rotate_bits_left(self, amount: Self) -> Result<Self, OperationError> {
+                Ok(self.rotate_left(shift_amount(self, amount, Self::BITS, "rol")?))
+            }
+
+            fn rotate_bits_right(self, amount: Self) -> Result<Self, OperationError> {
+                Ok(self.rotate_right(shift_amount(self, amount, Self::BITS, "ror")?))
+            }
+
+            fn count_ones(self) -> Self {
+                self.count_ones() as Self
+            }
+
+            fn leading_zeros(self) -> Self {
+                self.leading_zeros() as Self
+            }
+
+            fn trailing_zeros(self) -> Self {
+                self.trailing_zeros() as Self
             }
         }
-    }
+    };
 }
 
-impl_number_for_integer!(u8);
-impl_number_for_integer!(u16);
-impl_number_for_integer!(u32);
-impl_number_for_integer!(u64);
-impl_number_for_integer!(u128);
-impl_number_for_integer!(i8);
-impl_number_for_integer!(i16);
-impl_number_for_integer!(i32);
-impl_number_for_integer!(i64);
-impl_number_for_integer!(i128);
\ No newline at end of file
+impl_number_for_integer!(u8, u8, i8);
+impl_number_for_integer!(u16, u16, i16);
+impl_number_for_integer!(u32, u32, i32);
+impl_number_for_integer!(u64, u64, i64);
+impl_number_for_integer!(u128, u128, i128);
+impl_number_for_integer!(i8, u8, i8);
+impl_number_for_integer!(i16, u16, i16);
+impl_number_for_integer!(i32, u32, i32);
+impl_number_for_integer!(i64, u64, i64);
+impl_number_for_integer!(i128, u128, i128);
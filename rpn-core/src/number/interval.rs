@@ -0,0 +1,394 @@
+use crate::number::{Number, NumberError, Rational};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Closed interval `[lower, upper]` holding every value a measured or rounded number may take;
+/// arithmetic results are rounded outward so the true value always stays within bounds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval<N> {
+    lower: N,
+    upper: N,
+}
+
+/// Number that can bound an interval, widening inexact results away from the true value
+pub trait Endpoint: Number {
+    /// Nearest representable number below an inexact result
+    fn round_down(self) -> Self {
+        self
+    }
+
+    /// Nearest representable number above an inexact result
+    fn round_up(self) -> Self {
+        self
+    }
+
+    /// Quotient which [`Endpoint::round_down`] makes no greater than the exact one
+    fn divide_down(self, other: Self) -> Result<Self, OperationError> {
+        self.divide(other)
+    }
+
+    /// Quotient which [`Endpoint::round_up`] makes no less than the exact one
+    fn divide_up(self, other: Self) -> Result<Self, OperationError> {
+        self.divide(other)
+    }
+}
+
+macro_rules! impl_endpoint_for_float {
+    ($T:ty) => {
+        impl Endpoint for $T {
+            fn round_down(self) -> Self {
+                self.next_down()
+            }
+
+            fn round_up(self) -> Self {
+                self.next_up()
+            }
+        }
+    };
+}
+
+impl_endpoint_for_float!(f32);
+impl_endpoint_for_float!(f64);
+
+/// Quotient truncated toward zero along with whether the exact one is below it and above it
+fn truncated_quotient<N: Number>(a: N, b: N) -> Result<(N, bool, bool), OperationError> {
+    let quotient = a.clone().divide(b.clone())?;
+    let remainder = a.remainder(b.clone())?;
+    let below = (remainder < N::ZERO) != (b < N::ZERO);
+    let inexact = remainder != N::ZERO;
+    Ok((quotient, inexact && below, inexact && !below))
+}
+
+macro_rules! impl_endpoint_for_integer {
+    ($($T:ty),*) => {
+        $(impl Endpoint for $T {
+            /// Quotient rounded toward negative infinity rather than toward zero
+            fn divide_down(self, other: Self) -> Result<Self, OperationError> {
+                match truncated_quotient(self, other)? {
+                    (quotient, true, _) => quotient.subtract(1),
+                    (quotient, _, _) => Ok(quotient),
+                }
+            }
+
+            /// Quotient rounded toward positive infinity rather than toward zero
+            fn divide_up(self, other: Self) -> Result<Self, OperationError> {
+                match truncated_quotient(self, other)? {
+                    (quotient, _, true) => quotient.add(1),
+                    (quotient, _, _) => Ok(quotient),
+                }
+            }
+        })*
+    };
+}
+
+impl_endpoint_for_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<T: Number> Endpoint for Rational<T> {}
+
+impl<N: Endpoint> Interval<N> {
+    /// Creates an interval from its bounds, erring when they are out of order
+    pub fn new(lower: N, upper: N) -> Result<Self, OperationError> {
+        match lower.partial_cmp(&upper) {
+            Some(Ordering::Less | Ordering::Equal) => Ok(Self { lower, upper }),
+            _ => Err(OperationError::InvalidOperand),
+        }
+    }
+
+    /// Creates the interval holding only `value`, erring on values unordered with themselves
+    /// such as NaN
+    pub fn point(value: N) -> Result<Self, OperationError> {
+        Self::new(value.clone(), value)
+    }
+
+    /// Creates the interval `midpoint ± radius`
+    pub fn around(midpoint: N, radius: N) -> Result<Self, OperationError> {
+        let lower = midpoint.clone().subtract(radius.clone())?.round_down();
+        let upper = midpoint.add(radius)?.round_up();
+        Self::new(lower, upper)
+    }
+
+    pub fn lower(&self) -> &N {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &N {
+        &self.upper
+    }
+
+    pub fn contains(&self, value: &N) -> bool {
+        self.lower <= *value && *value <= self.upper
+    }
+
+    /// Value halfway between the bounds
+    pub fn midpoint(&self) -> Result<N, OperationError> {
+        let two = N::ONE.add(N::ONE)?;
+        self.lower.clone().add(self.upper.clone())?.divide(two)
+    }
+
+    /// Distance from the midpoint to the farthest bound, rounded up
+    pub fn radius(&self) -> Result<N, OperationError> {
+        let midpoint = self.midpoint()?;
+        let below = midpoint.clone().subtract(self.lower.clone())?.round_up();
+        let above = self.upper.clone().subtract(midpoint)?.round_up();
+        Ok(if below < above { above } else { below })
+    }
+
+    fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Smallest interval holding all four results of `f` applied to the bounds
+    fn hull(
+        self,
+        other: Self,
+        f: fn(N, N) -> Result<N, OperationError>,
+    ) -> Result<Self, OperationError> {
+        let (a, b, c, d) = (self.lower, self.upper, other.lower, other.upper);
+        let candidates = [
+            f(a.clone(), c.clone())?,
+            f(a, d.clone())?,
+            f(b.clone(), c)?,
+            f(b, d)?,
+        ];
+        let [first, rest @ ..] = candidates;
+        let (lower, upper) = rest
+            .into_iter()
+            .fold((first.clone(), first), |(lower, upper), n| {
+                match (n < lower, n > upper) {
+                    (true, _) => (n, upper),
+                    (_, true) => (lower, n),
+                    _ => (lower, upper),
+                }
+            });
+        Self::new(lower.round_down(), upper.round_up())
+    }
+}
+
+impl<N: Endpoint> Number for Interval<N> {
+    const ZERO: Self = Self {
+        lower: N::ZERO,
+        upper: N::ZERO,
+    };
+    const ONE: Self = Self {
+        lower: N::ONE,
+        upper: N::ONE,
+    };
+    const TEN: Self = Self {
+        lower: N::TEN,
+        upper: N::TEN,
+    };
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        Self::new(
+            self.lower.add(other.lower)?.round_down(),
+            self.upper.add(other.upper)?.round_up(),
+        )
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        Self::new(
+            self.lower.subtract(other.upper)?.round_down(),
+            self.upper.subtract(other.lower)?.round_up(),
+        )
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        self.hull(other, N::multiply)
+    }
+
+    /// Errs when the divisor contains zero, as the quotient would be unbounded
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        if other.contains(&N::ZERO) {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        let lower = self.clone().hull(other.clone(), N::divide_down)?.lower;
+        let upper = self.hull(other, N::divide_up)?.upper;
+        Self::new(lower, upper)
+    }
+
+    /// Only defined between exact values, as the remainder jumps within any wider interval
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        if !self.is_point() || !other.is_point() {
+            return Err(OperationError::InvalidOperand);
+        }
+        let remainder = self.lower.remainder(other.lower)?;
+        Self::new(remainder.clone().round_down(), remainder.round_up())
+    }
+
+    fn abs(self) -> Result<Self, OperationError> {
+        if self.lower >= N::ZERO {
+            Ok(self)
+        } else if self.upper <= N::ZERO {
+            self.negate()
+        } else {
+            let lower = self.lower.negate()?;
+            let upper = if lower > self.upper {
+                lower
+            } else {
+                self.upper
+            };
+            Self::new(N::ZERO, upper)
+        }
+    }
+}
+
+impl<N: Endpoint> PartialOrd for Interval<N> {
+    /// An interval is only less than another when entirely below it
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.upper < other.lower {
+            Some(Ordering::Less)
+        } else if self.lower > other.upper {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<N: Display + Endpoint> Display for Interval<N> {
+    /// Shows `midpoint ± radius`, falling back to the bounds when those cannot be computed or
+    /// when the midpoint was truncated, as that of integers `[1, 2]` would be
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_point() {
+            return Display::fmt(&self.lower, f);
+        }
+        let sum = self.lower.clone().add(self.upper.clone()).ok();
+        let exact = |midpoint: &N| midpoint.clone().add(midpoint.clone()).ok() == sum;
+        match (self.midpoint(), self.radius()) {
+            (Ok(midpoint), Ok(radius)) if exact(&midpoint) => {
+                f.write_fmt(format_args!("{midpoint} ± {radius}"))
+            }
+            _ => f.write_fmt(format_args!("[{}, {}]", self.lower, self.upper)),
+        }
+    }
+}
+
+impl<N: FromStr + Endpoint> FromStr for Interval<N> {
+    type Err = ParseIntervalError;
+
+    /// Parses a single value, `midpoint±radius`, `midpoint+-radius` or `[lower,upper]`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| s.trim().parse().map_err(|_| ParseIntervalError::Bound);
+        if let Some(bounds) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let (lower, upper) = bounds.split_once(',').ok_or(ParseIntervalError::Bound)?;
+            return Self::new(parse(lower)?, parse(upper)?).map_err(|_| ParseIntervalError::Order);
+        }
+        match s.split_once('±').or_else(|| s.split_once("+-")) {
+            Some((midpoint, radius)) => Self::around(parse(midpoint)?, parse(radius)?)
+                .map_err(|_| ParseIntervalError::Order),
+            None => Self::point(parse(s)?).map_err(|_| ParseIntervalError::Bound),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseIntervalError {
+    Bound,
+    Order,
+}
+
+impl Display for ParseIntervalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseIntervalError::Bound => f.write_str("Invalid interval bound"),
+            ParseIntervalError::Order => f.write_str("Interval bounds out of order"),
+        }
+    }
+}
+
+impl Error for ParseIntervalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    fn interval(lower: i32, upper: i32) -> Interval<i32> {
+        Interval::new(lower, upper).unwrap()
+    }
+
+    #[test]
+    fn arithmetic_bounds_every_result() {
+        assert_eq!(interval(1, 2).add(interval(3, 5)).unwrap(), interval(4, 7));
+        assert_eq!(
+            interval(1, 2).subtract(interval(3, 5)).unwrap(),
+            interval(-4, -1)
+        );
+        assert_eq!(
+            interval(-1, 2).multiply(interval(-3, 5)).unwrap(),
+            interval(-6, 10)
+        );
+        assert_eq!(
+            interval(6, 12).divide(interval(2, 3)).unwrap(),
+            interval(2, 6)
+        );
+    }
+
+    #[test]
+    fn integer_division_rounds_outward() {
+        assert_eq!(
+            interval(1, 1).divide(interval(2, 2)).unwrap(),
+            interval(0, 1)
+        );
+        assert_eq!(
+            interval(-7, 7).divide(interval(2, 2)).unwrap(),
+            interval(-4, 4)
+        );
+        assert_eq!(
+            interval(7, 7).divide(interval(-2, -2)).unwrap(),
+            interval(-4, -3)
+        );
+    }
+
+    #[test]
+    fn point_errs_on_nan() {
+        assert_matches!(
+            Interval::point(f64::NAN),
+            Err(OperationError::InvalidOperand)
+        );
+    }
+
+    #[test]
+    fn float_results_are_rounded_outward() {
+        let tenth = Interval::point(0.1).unwrap();
+        let sum = tenth.add(tenth).unwrap().add(tenth).unwrap();
+        assert!(sum.contains(&0.30000000000000004));
+        assert!(*sum.lower() < 0.3 && *sum.upper() > 0.3);
+    }
+
+    #[test]
+    fn divide_errs_on_divisor_containing_zero() {
+        let result = interval(1, 2).divide(interval(-1, 1));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn overlapping_intervals_are_unordered() {
+        assert!(interval(1, 2) < interval(3, 4));
+        assert_eq!(interval(1, 3).partial_cmp(&interval(2, 4)), None);
+    }
+
+    #[test]
+    fn parses_and_displays_midpoint_and_radius() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!("[1,3]".parse::<Interval<i32>>().unwrap(), interval(1, 3));
+        assert_eq!("2±1".parse::<Interval<i32>>().unwrap(), interval(1, 3));
+        assert_eq!("2+-1".parse::<Interval<i32>>().unwrap(), interval(1, 3));
+        assert_eq!(interval(1, 3).to_string(), "2 ± 1");
+        assert_eq!(interval(2, 2).to_string(), "2");
+        assert_eq!(interval(1, 2).to_string(), "[1, 2]");
+        assert_matches!(
+            "[3,1]".parse::<Interval<i32>>(),
+            Err(ParseIntervalError::Order)
+        );
+    }
+}
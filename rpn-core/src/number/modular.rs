@@ -0,0 +1,191 @@
+use crate::number::{Number, NumberError, Operands};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Integer modulo `M`, always kept as its representative in `0..M`, `M` being at least one
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Modular<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> Modular<M> {
+    pub const fn new(value: u64) -> Self {
+        const { assert!(M > 0, "modulus must be at least one") };
+        Self { value: value % M }
+    }
+
+    /// Representative in `0..M`
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Number which multiplied by this one gives 1, erring when the two share a factor with `M`
+    pub fn inverse(self) -> Result<Self, OperationError> {
+        let (mut r, mut next_r) = (i128::from(M), i128::from(self.value));
+        let (mut t, mut next_t) = (0i128, 1i128);
+        while next_r != 0 {
+            let quotient = r / next_r;
+            (r, next_r) = (next_r, r - quotient * next_r);
+            (t, next_t) = (next_t, t - quotient * next_t);
+        }
+        if r != 1 {
+            let operands = Operands::new(format_args!("inverse of {} mod {M}", self.value));
+            return Err(NumberError::NotInvertible(operands).into());
+        }
+        Ok(Self::new(t.rem_euclid(i128::from(M)) as u64))
+    }
+
+    /// Raises to the power of `exponent` by repeated squaring
+    pub fn power(self, exponent: u64) -> Self {
+        let (mut base, mut exponent, mut result) = (self, exponent, Self::new(1));
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.product(base);
+            }
+            base = base.product(base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn product(self, other: Self) -> Self {
+        let product = u128::from(self.value) * u128::from(other.value) % u128::from(M);
+        Self::new(product as u64)
+    }
+}
+
+impl<const M: u64> Number for Modular<M> {
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+    const TEN: Self = Self::new(10);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        let sum = (u128::from(self.value) + u128::from(other.value)) % u128::from(M);
+        Ok(Self::new(sum as u64))
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        let difference = u128::from(self.value) + u128::from(M) - u128::from(other.value);
+        Ok(Self::new((difference % u128::from(M)) as u64))
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        Ok(self.product(other))
+    }
+
+    /// Multiplies by the modular inverse of `other`
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        Ok(self.product(other.inverse()?))
+    }
+
+    /// Remainder of the division of the representatives, as there is no remainder in modular division
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        if other.value == 0 {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        Ok(Self::new(self.value % other.value))
+    }
+}
+
+impl<const M: u64> PartialOrd for Modular<M> {
+    /// Orders the representatives, as residues have no natural order
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const M: u64> Display for Modular<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<const M: u64> FromStr for Modular<M> {
+    type Err = ParseModularError;
+
+    /// Parses any integer, negative ones included, and reduces it modulo `M`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: i128 = s.parse().map_err(|_| ParseModularError)?;
+        Ok(Self::new(value.rem_euclid(i128::from(M)) as u64))
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseModularError;
+
+impl Display for ParseModularError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Invalid integer")
+    }
+}
+
+impl Error for ParseModularError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    type Mod7 = Modular<7>;
+
+    #[test]
+    fn arithmetic_wraps_around_modulus() {
+        assert_eq!(Mod7::new(5).add(Mod7::new(4)).unwrap(), Mod7::new(2));
+        assert_eq!(Mod7::new(2).subtract(Mod7::new(5)).unwrap(), Mod7::new(4));
+        assert_eq!(Mod7::new(3).multiply(Mod7::new(5)).unwrap(), Mod7::new(1));
+    }
+
+    #[test]
+    fn multiply_does_not_overflow_large_moduli() {
+        let big = Modular::<{ u64::MAX - 1 }>::new(u64::MAX - 2);
+        assert_eq!(big.multiply(big).unwrap(), Modular::new(1));
+    }
+
+    #[test]
+    fn divide_multiplies_by_inverse() {
+        assert_eq!(Mod7::new(3).inverse().unwrap(), Mod7::new(5));
+        assert_eq!(Mod7::new(6).divide(Mod7::new(3)).unwrap(), Mod7::new(2));
+    }
+
+    #[test]
+    fn inverse_error_names_number_and_modulus() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        let error = Modular::<12>::new(4).inverse().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Number error: Not invertible error in inverse of 4 mod 12"
+        );
+    }
+
+    #[test]
+    fn divide_errs_on_non_invertible_divisor() {
+        let result = Modular::<12>::new(5).divide(Modular::new(4));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotInvertible(_)))
+        );
+        let result = Mod7::ONE.divide(Mod7::ZERO);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotInvertible(_)))
+        );
+    }
+
+    #[test]
+    fn power_follows_fermat() {
+        assert_eq!(Mod7::new(3).power(6), Mod7::ONE);
+        assert_eq!(Mod7::new(3).power(0), Mod7::ONE);
+        assert_eq!(Mod7::new(3).power(5), Mod7::new(5));
+    }
+
+    #[test]
+    fn parses_negative_integers() {
+        assert_eq!("-1".parse::<Mod7>().unwrap(), Mod7::new(6));
+        assert_eq!("23".parse::<Mod7>().unwrap(), Mod7::new(2));
+        assert_matches!("x".parse::<Mod7>(), Err(ParseModularError));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::number::{Dimension, Number, NumberError, Operands, ParseUnitError, Unit, integer};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Number with a physical dimension, held in SI base units along with the unit
+/// it was entered or converted in, which it is displayed in
+#[derive(Clone, Copy, Debug)]
+pub struct Quantity<N> {
+    value: N,
+    dimension: Dimension,
+    unit: Option<Unit>,
+}
+
+/// Applies the unit conversion factor to a value, or its inverse when `inverse`, reduced to a
+/// single exact fraction first so integers are only off when the result is not an integer
+fn scale<N: Number>(value: N, unit: &Unit, inverse: bool) -> Result<N, OperationError> {
+    let overflow = || NumberError::Overflow(Operands::new(format_args!("factor of {unit}")));
+    let (numerator, denominator) = unit.factors().try_fold(
+        (1u128, 1u128),
+        |fraction, (numerator, denominator, exponent)| {
+            (0..exponent).try_fold(fraction, |(a, b), _| {
+                let (c, d) = (u128::from(numerator), u128::from(denominator));
+                let (ad, cb) = (gcd(a, d), gcd(c, b));
+                let a = (a / ad).checked_mul(c / cb).ok_or_else(overflow)?;
+                let b = (b / cb).checked_mul(d / ad).ok_or_else(overflow)?;
+                Ok::<_, OperationError>((a, b))
+            })
+        },
+    )?;
+    let (numerator, denominator): (N, N) = match inverse {
+        false => (integer(numerator)?, integer(denominator)?),
+        true => (integer(denominator)?, integer(numerator)?),
+    };
+    let product = value.multiply(numerator)?;
+    let quotient = product.clone().divide(denominator.clone())?;
+    // Division rounding to integers, as that of one by two, cannot give fractions
+    let integral = N::ONE.divide(N::ONE.add(N::ONE)?)? == N::ZERO;
+    if integral && product.remainder(denominator)? != N::ZERO {
+        return Err(NumberError::InexactResult(Operands::new(format_args!("{unit}"))).into());
+    }
+    Ok(quotient)
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<N: Number> Quantity<N> {
+    /// Quantity of `value` in SI base units of the given dimension
+    pub const fn new(value: N, dimension: Dimension) -> Self {
+        Self {
+            value,
+            dimension,
+            unit: None,
+        }
+    }
+
+    /// Quantity of `value` in the given unit
+    pub fn with_unit(value: N, unit: Unit) -> Result<Self, OperationError> {
+        Ok(Self {
+            value: scale(value, &unit, false)?,
+            dimension: unit.dimension()?,
+            unit: Some(unit),
+        })
+    }
+
+    /// Value in SI base units
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    pub fn unit(&self) -> Option<&Unit> {
+        self.unit.as_ref()
+    }
+
+    /// Value in the unit the quantity is displayed in
+    pub fn value_in_unit(&self) -> Result<N, OperationError> {
+        match self.unit {
+            Some(ref unit) => scale(self.value.clone(), unit, true),
+            None => Ok(self.value.clone()),
+        }
+    }
+
+    /// Same quantity displayed in the unit of `target`, erring when the dimensions differ
+    pub fn convert(self, target: Self) -> Result<Self, OperationError> {
+        if self.dimension != target.dimension {
+            return Err(OperationError::IncompatibleDimensions);
+        }
+        Ok(Self {
+            unit: target.unit,
+            ..self
+        })
+    }
+
+    fn matching(&self, other: &Self) -> Result<(), OperationError> {
+        if self.dimension == other.dimension {
+            Ok(())
+        } else {
+            Err(OperationError::IncompatibleDimensions)
+        }
+    }
+}
+
+impl<N: Number> From<N> for Quantity<N> {
+    fn from(value: N) -> Self {
+        Self::new(value, Dimension::NONE)
+    }
+}
+
+impl<N: Number> Number for Quantity<N> {
+    const ZERO: Self = Self::new(N::ZERO, Dimension::NONE);
+    const ONE: Self = Self::new(N::ONE, Dimension::NONE);
+    const TEN: Self = Self::new(N::TEN, Dimension::NONE);
+
+    /// Adds quantities of the same dimension, keeping the unit of the first one
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        self.matching(&other)?;
+        Ok(Self {
+            value: self.value.add(other.value)?,
+            ..self
+        })
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        self.matching(&other)?;
+        Ok(Self {
+            value: self.value.subtract(other.value)?,
+            ..self
+        })
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        let dimension = self.dimension.multiply(other.dimension)?;
+        Ok(Self::new(self.value.multiply(other.value)?, dimension))
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        let dimension = self.dimension.divide(other.dimension)?;
+        Ok(Self::new(self.value.divide(other.value)?, dimension))
+    }
+
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        self.matching(&other)?;
+        Ok(Self {
+            value: self.value.remainder(other.value)?,
+            ..self
+        })
+    }
+
+    fn negate(self) -> Result<Self, OperationError> {
+        Ok(Self {
+            value: self.value.negate()?,
+            ..self
+        })
+    }
+
+    fn abs(self) -> Result<Self, OperationError> {
+        Ok(Self {
+            value: self.value.abs()?,
+            ..self
+        })
+    }
+}
+
+impl<N: Number> PartialEq for Quantity<N> {
+    /// Quantities are equal whatever unit they are displayed in
+    fn eq(&self, other: &Self) -> bool {
+        self.dimension == other.dimension && self.value == other.value
+    }
+}
+
+impl<N: Number> PartialOrd for Quantity<N> {
+    /// Only quantities of the same dimension are ordered
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.dimension == other.dimension {
+            true => self.value.partial_cmp(&other.value),
+            false => None,
+        }
+    }
+}
+
+impl<N: Display + Number> Display for Quantity<N> {
+    /// Shows the value in its unit, falling back to SI base units when it cannot be converted
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (&self.unit, self.value_in_unit()) {
+            (Some(unit), Ok(value)) => f.write_fmt(format_args!("{value} {unit}")),
+            _ if self.dimension == Dimension::NONE => Display::fmt(&self.value, f),
+            _ => f.write_fmt(format_args!("{} {}", self.value, self.dimension)),
+        }
+    }
+}
+
+impl<N: FromStr + Number> FromStr for Quantity<N> {
+    type Err = ParseQuantityError;
+
+    /// Parses a number directly followed by a unit as in `100km/h`, a plain number,
+    /// or a unit alone which stands for one of it
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse::<N>() {
+            return Ok(Self::from(value));
+        }
+        let mut error = ParseQuantityError::Unit(ParseUnitError::UnknownUnit);
+        for (i, _) in s.char_indices() {
+            let Ok(unit) = s[i..].parse() else {
+                continue;
+            };
+            let value = match &s[..i] {
+                "" => N::ONE,
+                value => match value.parse() {
+                    Ok(value) => value,
+                    Err(_) => {
+                        error = ParseQuantityError::Value;
+                        continue;
+                    }
+                },
+            };
+            return Self::with_unit(value, unit).map_err(|_| ParseQuantityError::Value);
+        }
+        Err(error)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseQuantityError {
+    Value,
+    Unit(ParseUnitError),
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseQuantityError::Value => f.write_str("Invalid quantity value"),
+            ParseQuantityError::Unit(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseQuantityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseQuantityError::Value => None,
+            ParseQuantityError::Unit(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    fn quantity(s: &str) -> Quantity<f64> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn divide_combines_dimensions() {
+        let speed = quantity("3m").divide(quantity("2s")).unwrap();
+        assert_eq!(speed.to_string(), "1.5 m/s");
+    }
+
+    #[test]
+    fn add_errs_on_incompatible_dimensions() {
+        let result = quantity("3m").add(quantity("2s"));
+        assert_matches!(result, Err(OperationError::IncompatibleDimensions));
+    }
+
+    #[test]
+    fn add_converts_units() {
+        let length = quantity("1km").add(quantity("500m")).unwrap();
+        assert_eq!(length.to_string(), "1.5 km");
+    }
+
+    #[test]
+    fn convert_changes_displayed_unit() {
+        let speed = Quantity::<i64>::from_str("36km/h").unwrap();
+        let speed = speed.convert("m/s".parse().unwrap()).unwrap();
+        assert_eq!(speed.to_string(), "10 m/s");
+        let result = speed.convert("kg".parse().unwrap());
+        assert_matches!(result, Err(OperationError::IncompatibleDimensions));
+    }
+
+    #[test]
+    fn with_unit_errs_on_inexact_integers() {
+        let length = Quantity::<i64>::with_unit(5, "ft".parse().unwrap());
+        assert_matches!(
+            length,
+            Err(OperationError::Number(NumberError::InexactResult(_)))
+        );
+        let length = Quantity::<i64>::with_unit(1250, "ft".parse().unwrap()).unwrap();
+        assert_eq!(*length.value(), 381);
+        assert_eq!(length.to_string(), "1250 ft");
+    }
+
+    #[test]
+    fn parses_quantities() {
+        assert_eq!(*quantity("1e3mm").value(), 1.);
+        assert_eq!(*quantity("2ft").value(), 0.6096);
+        assert_eq!(quantity("4").dimension(), Dimension::NONE);
+        assert_matches!(
+            "3xyz".parse::<Quantity<f64>>(),
+            Err(ParseQuantityError::Unit(_))
+        );
+    }
+}
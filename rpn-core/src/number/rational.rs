@@ -0,0 +1,397 @@
+use crate::number::{Bounded, Number, NumberError};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Exact fraction over an integer number, always kept normalized:
+/// numerator and denominator share no common factor and the denominator is positive
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Number> Rational<T> {
+    /// Creates a normalized fraction, erring on a zero denominator
+    pub fn new(numerator: T, denominator: T) -> Result<Self, OperationError> {
+        if denominator == T::ZERO {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        let divisor = gcd(numerator.clone(), denominator.clone())?;
+        let mut numerator = numerator.divide(divisor.clone())?;
+        let mut denominator = denominator.divide(divisor)?;
+        if denominator < T::ZERO {
+            numerator = negate(numerator)?;
+            denominator = negate(denominator)?;
+        }
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> &T {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &T {
+        &self.denominator
+    }
+
+    const fn integer(value: T) -> Self {
+        Self {
+            numerator: value,
+            denominator: T::ONE,
+        }
+    }
+
+    fn reciprocal(self) -> Result<Self, OperationError> {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// Integer part of the fraction, rounded toward zero
+    fn truncate(self) -> Result<T, OperationError> {
+        self.numerator.divide(self.denominator)
+    }
+
+    /// Displays the fraction in decimal notation with exactly `digits` fractional digits,
+    /// truncated toward zero
+    pub fn decimal_expansion(&self, digits: usize) -> DecimalExpansion<'_, T> {
+        DecimalExpansion {
+            rational: self,
+            digits,
+        }
+    }
+}
+
+impl<T: Number> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl<T: Number> Number for Rational<T> {
+    const ZERO: Self = Self::integer(T::ZERO);
+    const ONE: Self = Self::integer(T::ONE);
+    const TEN: Self = Self::integer(T::TEN);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        let (left, right, denominator) = common_denominator(&self, &other)?;
+        let numerator = self
+            .numerator
+            .multiply(left)
+            .and_then(|n| n.add(other.numerator.multiply(right)?))?;
+        Self::new(numerator, denominator)
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        let (left, right, denominator) = common_denominator(&self, &other)?;
+        let numerator = self
+            .numerator
+            .multiply(left)
+            .and_then(|n| n.subtract(other.numerator.multiply(right)?))?;
+        Self::new(numerator, denominator)
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        let first = gcd(self.numerator.clone(), other.denominator.clone())?;
+        let second = gcd(other.numerator.clone(), self.denominator.clone())?;
+        let numerator = self
+            .numerator
+            .divide(first.clone())
+            .and_then(|n| n.multiply(other.numerator.divide(second.clone())?))?;
+        let denominator = self
+            .denominator
+            .divide(second)
+            .and_then(|d| d.multiply(other.denominator.divide(first)?))?;
+        Self::new(numerator, denominator)
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        self.multiply(other.reciprocal()?)
+    }
+
+    /// Remainder of the division truncated toward zero, so the result has the sign of `self`
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        let quotient = self.clone().divide(other.clone())?.truncate()?;
+        self.subtract(other.multiply(Self::integer(quotient))?)
+    }
+}
+
+impl<T: Bounded> Bounded for Rational<T> {
+    const MIN: Self = Self::integer(T::MIN);
+    const MAX: Self = Self::integer(T::MAX);
+}
+
+impl<T: Number> PartialOrd for Rational<T> {
+    /// Compares through the continued fraction expansions of both sides, which never overflows
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (mut a, mut b) = (self.numerator.clone(), self.denominator.clone());
+        let (mut c, mut d) = (other.numerator.clone(), other.denominator.clone());
+        loop {
+            let (first_quotient, first_remainder) = floor_divide(a, b.clone())?;
+            let (second_quotient, second_remainder) = floor_divide(c, d.clone())?;
+            if first_quotient != second_quotient {
+                return first_quotient.partial_cmp(&second_quotient);
+            }
+            match (
+                first_remainder == T::ZERO,
+                second_remainder == T::ZERO,
+            ) {
+                (true, true) => return Some(Ordering::Equal),
+                (true, false) => return Some(Ordering::Less),
+                (false, true) => return Some(Ordering::Greater),
+                (false, false) => {
+                    (a, b, c, d) = (d, second_remainder, b, first_remainder);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Display + Number> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.denominator == T::ONE {
+            f.write_fmt(format_args!("{}", self.numerator))
+        } else {
+            f.write_fmt(format_args!("{}/{}", self.numerator, self.denominator))
+        }
+    }
+}
+
+pub struct DecimalExpansion<'a, T> {
+    rational: &'a Rational<T>,
+    digits: usize,
+}
+
+impl<T: Number> DecimalExpansion<'_, T> {
+    /// Integer part of the fraction and absolute value of the remainder
+    fn split(&self) -> Result<(T, T), OperationError> {
+        let numerator = self.rational.numerator.clone();
+        let denominator = self.rational.denominator.clone();
+        let integer = numerator.clone().divide(denominator.clone())?;
+        let remainder = numerator.remainder(denominator)?;
+        match remainder < T::ZERO {
+            true => Ok((integer, negate(remainder)?)),
+            false => Ok((integer, remainder)),
+        }
+    }
+
+    /// Next digit of the long division of `remainder` by the denominator, along with the new
+    /// remainder, multiplying by ten as ten additions modulo the denominator so that no partial
+    /// result ever exceeds it
+    fn next_digit(&self, remainder: &T) -> Result<(T, T), OperationError> {
+        let denominator = &self.rational.denominator;
+        let wrap = denominator.clone().subtract(remainder.clone())?;
+        let (mut digit, mut shifted) = (T::ZERO, T::ZERO);
+        for _ in 0..10 {
+            if shifted >= wrap {
+                (digit, shifted) = (digit.add(T::ONE)?, shifted.subtract(wrap.clone())?);
+            } else {
+                shifted = shifted.add(remainder.clone())?;
+            }
+        }
+        Ok((digit, shifted))
+    }
+}
+
+/// Falls back to the plain fraction when the digits cannot be computed, so that displaying
+/// never fails
+impl<T: Display + Number> Display for DecimalExpansion<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Ok((integer, remainder)) = self.split() else {
+            return Display::fmt(self.rational, f);
+        };
+        let digits = (0..self.digits).try_fold(remainder.clone(), |remainder, _| {
+            self.next_digit(&remainder).map(|(_, remainder)| remainder)
+        });
+        if digits.is_err() {
+            return Display::fmt(self.rational, f);
+        }
+        if self.rational.numerator < T::ZERO && integer == T::ZERO {
+            f.write_str("-")?;
+        }
+        f.write_fmt(format_args!("{integer}"))?;
+        if self.digits > 0 {
+            f.write_str(".")?;
+        }
+        let mut remainder = remainder;
+        for _ in 0..self.digits {
+            let Ok((digit, next)) = self.next_digit(&remainder) else {
+                break;
+            };
+            f.write_fmt(format_args!("{digit}"))?;
+            remainder = next;
+        }
+        Ok(())
+    }
+}
+
+impl<T: FromStr + Number> FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numerator, denominator) = s.split_once('/').unwrap_or((s, "1"));
+        let numerator = numerator
+            .parse()
+            .map_err(|_| ParseRationalError::Numerator)?;
+        let denominator = denominator
+            .parse()
+            .map_err(|_| ParseRationalError::Denominator)?;
+        Self::new(numerator, denominator).map_err(|e| match e {
+            OperationError::Number(NumberError::DivisionByZero) => {
+                ParseRationalError::ZeroDenominator
+            }
+            _ => ParseRationalError::Overflow,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseRationalError {
+    Numerator,
+    Denominator,
+    ZeroDenominator,
+    Overflow,
+}
+
+impl Display for ParseRationalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseRationalError::Numerator => f.write_str("Invalid numerator"),
+            ParseRationalError::Denominator => f.write_str("Invalid denominator"),
+            ParseRationalError::ZeroDenominator => f.write_str("Zero denominator"),
+            ParseRationalError::Overflow => f.write_str("Fraction overflows"),
+        }
+    }
+}
+
+impl Error for ParseRationalError {}
+
+fn negate<T: Number>(value: T) -> Result<T, OperationError> {
+    T::ZERO.subtract(value)
+}
+
+/// Factors bringing both fractions to their least common denominator, along with that denominator
+fn common_denominator<T: Number>(
+    first: &Rational<T>,
+    second: &Rational<T>,
+) -> Result<(T, T, T), OperationError> {
+    let divisor = gcd(first.denominator.clone(), second.denominator.clone())?;
+    let left = second.denominator.clone().divide(divisor.clone())?;
+    let right = first.denominator.clone().divide(divisor)?;
+    let denominator = first.denominator.clone().multiply(left.clone())?;
+    Ok((left, right, denominator))
+}
+
+/// Greatest common divisor, never zero so it can always be divided by
+fn gcd<T: Number>(mut a: T, mut b: T) -> Result<T, OperationError> {
+    while b != T::ZERO {
+        let remainder = a.remainder(b.clone())?;
+        (a, b) = (b, remainder);
+    }
+    if a == T::ZERO {
+        Ok(T::ONE)
+    } else if a < T::ZERO {
+        negate(a)
+    } else {
+        Ok(a)
+    }
+}
+
+/// Quotient rounded toward negative infinity along with its non-negative remainder
+fn floor_divide<T: Number>(a: T, b: T) -> Option<(T, T)> {
+    let quotient = a.clone().divide(b.clone()).ok()?;
+    let remainder = a.remainder(b.clone()).ok()?;
+    if remainder < T::ZERO {
+        Some((quotient.subtract(T::ONE).ok()?, remainder.add(b).ok()?))
+    } else {
+        Some((quotient, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    fn rational(numerator: i32, denominator: i32) -> Rational<i32> {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn new_normalizes_fraction() {
+        let value = rational(6, -8);
+        assert_eq!((*value.numerator(), *value.denominator()), (-3, 4));
+    }
+
+    #[test]
+    fn new_errs_on_zero_denominator() {
+        let result = Rational::new(1, 0);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn add_is_exact() {
+        let value = rational(1, 3).add(rational(1, 6)).unwrap();
+        assert_eq!(value, rational(1, 2));
+    }
+
+    #[test]
+    fn remainder_truncates_toward_zero() {
+        let value = rational(-7, 2).remainder(rational(1, 1)).unwrap();
+        assert_eq!(value, rational(-1, 2));
+    }
+
+    #[test]
+    fn multiply_errs_with_overflow() {
+        let result = rational(i8::MAX as i32, 1)
+            .multiply(rational(i32::MAX, 1));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn comparison_does_not_overflow() {
+        assert!(rational(i32::MAX - 1, i32::MAX) > rational(i32::MAX - 2, i32::MAX - 1));
+        assert!(rational(-1, 3) < rational(-1, 4));
+    }
+
+    #[test]
+    fn decimal_expansion_truncates_to_requested_digits() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!(rational(2, 3).decimal_expansion(4).to_string(), "0.6666");
+        assert_eq!(rational(-1, 8).decimal_expansion(2).to_string(), "-0.12");
+        assert_eq!(rational(7, 2).decimal_expansion(0).to_string(), "3");
+    }
+
+    #[test]
+    fn decimal_expansion_does_not_overflow() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        let value = Rational::new(i64::MAX - 1, i64::MAX).unwrap();
+        assert_eq!(value.decimal_expansion(4).to_string(), "0.9999");
+        let value = Rational::new(i64::MIN + 1, i64::MAX).unwrap();
+        assert_eq!(value.decimal_expansion(2).to_string(), "-1.00");
+        let value = Rational::new(-1, i64::MAX).unwrap();
+        assert_eq!(value.decimal_expansion(2).to_string(), "-0.00");
+    }
+
+    #[test]
+    fn parses_fraction_literal() {
+        assert_eq!("3/4".parse::<Rational<i32>>().unwrap(), rational(3, 4));
+        assert_eq!("-6/8".parse::<Rational<i32>>().unwrap(), rational(-3, 4));
+        assert_matches!(
+            "1/0".parse::<Rational<i32>>(),
+            Err(ParseRationalError::ZeroDenominator)
+        );
+    }
+}
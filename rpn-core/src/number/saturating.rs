@@ -0,0 +1,179 @@
+use crate::number::{Bounded, Integer, Number, NumberError, Power};
+use crate::operation::OperationError;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Integer whose arithmetic stops at the bounds of its type instead of overflowing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! impl_number_for_saturating {
+    ($($T:ty),*) => {
+        $(
+            impl Number for Saturating<$T> {
+                const ZERO: Self = Self(0);
+                const ONE: Self = Self(1);
+                const TEN: Self = Self(10);
+
+                fn add(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.saturating_add(other.0)))
+                }
+
+                fn subtract(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.saturating_sub(other.0)))
+                }
+
+                fn multiply(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.saturating_mul(other.0)))
+                }
+
+                /// `MIN / -1` saturates to `MAX`
+                fn divide(self, other: Self) -> Result<Self, OperationError> {
+                    if other.0 == 0 {
+                        return Err(NumberError::DivisionByZero.into());
+                    }
+                    Ok(Self(self.0.saturating_div(other.0)))
+                }
+
+                fn remainder(self, other: Self) -> Result<Self, OperationError> {
+                    if other.0 == 0 {
+                        return Err(NumberError::DivisionByZero.into());
+                    }
+                    // `MIN % -1` overflows while computing the quotient, but its remainder is zero
+                    Ok(Self(self.0.checked_rem(other.0).unwrap_or(0)))
+                }
+            }
+
+            impl Bounded for Saturating<$T> {
+                const MIN: Self = Self(<$T>::MIN);
+                const MAX: Self = Self(<$T>::MAX);
+            }
+
+            impl Power for Saturating<$T> {
+                /// Negative exponents are left to checked powers, which never overflow on them,
+                /// while the others are raised by repeated squaring to handle any exponent
+                fn power(self, exponent: Self) -> Result<Self, OperationError> {
+                    if exponent < Self::ZERO {
+                        return self.0.power(exponent.0).map(Self);
+                    }
+                    let (mut base, mut exponent, mut result) = (self.0, exponent.0, Self::ONE.0);
+                    while exponent > 0 {
+                        if exponent & 1 == 1 {
+                            result = result.saturating_mul(base);
+                        }
+                        base = base.saturating_mul(base);
+                        exponent >>= 1;
+                    }
+                    Ok(Self(result))
+                }
+
+                fn nth_root(self, n: Self) -> Result<Self, OperationError> {
+                    self.0.nth_root(n.0).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_saturating!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Bits are the same as those of the underlying integer
+impl<T: Integer> Integer for Saturating<T>
+where
+    Self: Bounded,
+{
+    fn and(self, other: Self) -> Self {
+        Self(self.0.and(other.0))
+    }
+
+    fn or(self, other: Self) -> Self {
+        Self(self.0.or(other.0))
+    }
+
+    fn xor(self, other: Self) -> Self {
+        Self(self.0.xor(other.0))
+    }
+
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+
+    fn shift_left(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.shift_left(amount.0).map(Self)
+    }
+
+    fn shift_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.shift_right(amount.0).map(Self)
+    }
+
+    fn arithmetic_shift_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.arithmetic_shift_right(amount.0).map(Self)
+    }
+
+    fn rotate_bits_left(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.rotate_bits_left(amount.0).map(Self)
+    }
+
+    fn rotate_bits_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.rotate_bits_right(amount.0).map(Self)
+    }
+
+    fn count_ones(self) -> Self {
+        Self(self.0.count_ones())
+    }
+
+    fn leading_zeros(self) -> Self {
+        Self(self.0.leading_zeros())
+    }
+
+    fn trailing_zeros(self) -> Self {
+        Self(self.0.trailing_zeros())
+    }
+}
+
+impl<T: Display> Display for Saturating<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: FromStr> FromStr for Saturating<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn arithmetic_stops_at_bounds() {
+        let sum = Saturating(250u8).add(Saturating(10)).unwrap();
+        assert_eq!(sum, Saturating(255));
+        let difference = Saturating(0u32).subtract(Saturating(1)).unwrap();
+        assert_eq!(difference, Saturating(0));
+        let quotient = Saturating(i8::MIN).divide(Saturating(-1)).unwrap();
+        assert_eq!(quotient, Saturating(i8::MAX));
+        let remainder = Saturating(i8::MIN).remainder(Saturating(-1)).unwrap();
+        assert_eq!(remainder, Saturating(0));
+        let power = Saturating(-3i8).power(Saturating(5)).unwrap();
+        assert_eq!(power, Saturating(i8::MIN));
+        let power = Saturating(2u64).power(Saturating(1 << 33)).unwrap();
+        assert_eq!(power, Saturating(u64::MAX));
+        let power = Saturating(-2i64).power(Saturating((1 << 33) + 1)).unwrap();
+        assert_eq!(power, Saturating(i64::MIN));
+    }
+
+    #[test]
+    fn divide_errs_on_zero() {
+        let result = Saturating(1u8).divide(Saturating(0));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+}
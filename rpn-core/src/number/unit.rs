@@ -0,0 +1,277 @@
+use crate::number::{NumberError, Operands};
+use crate::operation::OperationError;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Symbols of the SI base units, in the order of the dimension exponents
+const BASE_SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// Physical dimension as the exponents of the seven SI base units:
+/// length, mass, time, electric current, temperature, amount of substance and luminous intensity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dimension([i8; 7]);
+
+impl Dimension {
+    pub const NONE: Self = Self([0; 7]);
+
+    pub const fn new(exponents: [i8; 7]) -> Self {
+        Self(exponents)
+    }
+
+    pub fn exponents(&self) -> [i8; 7] {
+        self.0
+    }
+
+    /// Dimension of a product, erring when an exponent overflows
+    pub fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        self.combine(other, i8::checked_add)
+    }
+
+    /// Dimension of a quotient, erring when an exponent overflows
+    pub fn divide(self, other: Self) -> Result<Self, OperationError> {
+        self.combine(other, i8::checked_sub)
+    }
+
+    /// Dimension raised to an integer power, erring when an exponent overflows
+    pub fn power(self, exponent: i8) -> Result<Self, OperationError> {
+        self.combine(Self([exponent; 7]), i8::checked_mul)
+    }
+
+    fn combine(self, other: Self, f: fn(i8, i8) -> Option<i8>) -> Result<Self, OperationError> {
+        let mut exponents = [0; 7];
+        for (exponent, (a, b)) in exponents.iter_mut().zip(self.0.into_iter().zip(other.0)) {
+            *exponent = f(a, b).ok_or(NumberError::Overflow(Operands::NONE))?;
+        }
+        Ok(Self(exponents))
+    }
+}
+
+impl Display for Dimension {
+    /// Shows the dimension in SI base units, as in `kg*m/s^2`
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let terms = BASE_SYMBOLS.into_iter().zip(self.0);
+        write_terms(f, terms.clone().filter(|&(_, e)| e > 0))?;
+        write_denominator(f, terms.filter(|&(_, e)| e < 0))
+    }
+}
+
+/// Unit that quantities can be written in, with the factor converting it to SI base units
+/// as an exact fraction
+#[derive(Debug)]
+pub struct UnitDefinition {
+    pub symbol: &'static str,
+    pub numerator: u64,
+    pub denominator: u64,
+    pub dimension: Dimension,
+}
+
+const fn unit(symbol: &'static str, factor: (u64, u64), exponents: [i8; 7]) -> UnitDefinition {
+    UnitDefinition {
+        symbol,
+        numerator: factor.0,
+        denominator: factor.1,
+        dimension: Dimension(exponents),
+    }
+}
+
+/// Every unit that can be parsed
+pub const UNITS: [UnitDefinition; 29] = [
+    unit("m", (1, 1), [1, 0, 0, 0, 0, 0, 0]),
+    unit("km", (1000, 1), [1, 0, 0, 0, 0, 0, 0]),
+    unit("cm", (1, 100), [1, 0, 0, 0, 0, 0, 0]),
+    unit("mm", (1, 1000), [1, 0, 0, 0, 0, 0, 0]),
+    unit("in", (254, 10000), [1, 0, 0, 0, 0, 0, 0]),
+    unit("ft", (3048, 10000), [1, 0, 0, 0, 0, 0, 0]),
+    unit("yd", (9144, 10000), [1, 0, 0, 0, 0, 0, 0]),
+    unit("mi", (1609344, 1000), [1, 0, 0, 0, 0, 0, 0]),
+    unit("kg", (1, 1), [0, 1, 0, 0, 0, 0, 0]),
+    unit("g", (1, 1000), [0, 1, 0, 0, 0, 0, 0]),
+    unit("t", (1000, 1), [0, 1, 0, 0, 0, 0, 0]),
+    unit("lb", (45359237, 100000000), [0, 1, 0, 0, 0, 0, 0]),
+    unit("oz", (28349523125, 1000000000000), [0, 1, 0, 0, 0, 0, 0]),
+    unit("s", (1, 1), [0, 0, 1, 0, 0, 0, 0]),
+    unit("min", (60, 1), [0, 0, 1, 0, 0, 0, 0]),
+    unit("h", (3600, 1), [0, 0, 1, 0, 0, 0, 0]),
+    unit("d", (86400, 1), [0, 0, 1, 0, 0, 0, 0]),
+    unit("A", (1, 1), [0, 0, 0, 1, 0, 0, 0]),
+    unit("K", (1, 1), [0, 0, 0, 0, 1, 0, 0]),
+    unit("mol", (1, 1), [0, 0, 0, 0, 0, 1, 0]),
+    unit("cd", (1, 1), [0, 0, 0, 0, 0, 0, 1]),
+    unit("L", (1, 1000), [3, 0, 0, 0, 0, 0, 0]),
+    unit("Hz", (1, 1), [0, 0, -1, 0, 0, 0, 0]),
+    unit("N", (1, 1), [1, 1, -2, 0, 0, 0, 0]),
+    unit("J", (1, 1), [2, 1, -2, 0, 0, 0, 0]),
+    unit("W", (1, 1), [2, 1, -3, 0, 0, 0, 0]),
+    unit("Pa", (1, 1), [-1, 1, -2, 0, 0, 0, 0]),
+    unit("C", (1, 1), [0, 0, 1, 1, 0, 0, 0]),
+    unit("V", (1, 1), [2, 1, -3, -1, 0, 0, 0]),
+];
+
+/// Most unit symbols a compound unit can be made of
+const MAX_TERMS: usize = 4;
+
+/// Compound unit such as `km/h` or `kg*m/s^2`, as powers of units from [`UNITS`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unit {
+    terms: [(u8, i8); MAX_TERMS],
+    len: u8,
+}
+
+impl Unit {
+    fn terms(&self) -> impl Iterator<Item = (&'static UnitDefinition, i8)> + Clone {
+        self.terms[..usize::from(self.len)]
+            .iter()
+            .map(|&(index, power)| (&UNITS[usize::from(index)], power))
+    }
+
+    pub fn dimension(&self) -> Result<Dimension, OperationError> {
+        self.terms()
+            .try_fold(Dimension::NONE, |dimension, (unit, power)| {
+                dimension.multiply(unit.dimension.power(power)?)
+            })
+    }
+
+    /// Factor converting this unit to SI base units, as the powers of every unit's fraction
+    /// that end up in the numerator and in the denominator
+    pub fn factors(&self) -> impl Iterator<Item = (u64, u64, u8)> + Clone {
+        self.terms().map(|(unit, power)| {
+            let exponent = power.unsigned_abs();
+            if power > 0 {
+                (unit.numerator, unit.denominator, exponent)
+            } else {
+                (unit.denominator, unit.numerator, exponent)
+            }
+        })
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let terms = self.terms().map(|(unit, power)| (unit.symbol, power));
+        write_terms(f, terms.clone().filter(|&(_, p)| p > 0))?;
+        write_denominator(f, terms.filter(|&(_, p)| p < 0))
+    }
+}
+
+fn write_power(f: &mut Formatter<'_>, symbol: &str, power: i8) -> core::fmt::Result {
+    match power.unsigned_abs() {
+        1 => f.write_str(symbol),
+        power => f.write_fmt(format_args!("{symbol}^{power}")),
+    }
+}
+
+fn write_terms<'a>(
+    f: &mut Formatter<'_>,
+    terms: impl Iterator<Item = (&'a str, i8)>,
+) -> core::fmt::Result {
+    for (i, (symbol, power)) in terms.enumerate() {
+        if i > 0 {
+            f.write_str("*")?;
+        }
+        write_power(f, symbol, power)?;
+    }
+    Ok(())
+}
+
+/// Writes every negative power after its own `/`, which is how they are parsed back
+fn write_denominator<'a>(
+    f: &mut Formatter<'_>,
+    terms: impl Iterator<Item = (&'a str, i8)>,
+) -> core::fmt::Result {
+    for (symbol, power) in terms {
+        f.write_str("/")?;
+        write_power(f, symbol, power)?;
+    }
+    Ok(())
+}
+
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    /// Parses symbols from [`UNITS`] with optional integer powers, multiplied with `*`;
+    /// every term after a `/` is in the denominator, so `J/kg/K` is `J/(kg*K)`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut unit = Unit {
+            terms: [(0, 0); MAX_TERMS],
+            len: 0,
+        };
+        let (numerator, denominator) = s.split_once('/').unwrap_or((s, ""));
+        let numerator = numerator.split('*').map(|term| (term, 1));
+        let denominator = denominator.split(['*', '/']).map(|term| (term, -1));
+        for (term, sign) in numerator.chain(denominator.filter(|&(t, _)| !t.is_empty())) {
+            let (symbol, power) = term.split_once('^').unwrap_or((term, "1"));
+            let index = UNITS
+                .iter()
+                .position(|u| u.symbol == symbol)
+                .ok_or(ParseUnitError::UnknownUnit)?;
+            let power: i8 = power.parse().map_err(|_| ParseUnitError::InvalidPower)?;
+            let slot = unit
+                .terms
+                .get_mut(usize::from(unit.len))
+                .ok_or(ParseUnitError::TooManyTerms)?;
+            let power = power.checked_mul(sign).ok_or(ParseUnitError::InvalidPower)?;
+            *slot = (index as u8, power);
+            unit.len += 1;
+        }
+        unit.dimension().map_err(|_| ParseUnitError::InvalidPower)?;
+        Ok(unit)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseUnitError {
+    UnknownUnit,
+    InvalidPower,
+    TooManyTerms,
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseUnitError::UnknownUnit => f.write_str("Unknown unit"),
+            ParseUnitError::InvalidPower => f.write_str("Invalid unit power"),
+            ParseUnitError::TooManyTerms => f.write_str("Too many units in compound unit"),
+        }
+    }
+}
+
+impl Error for ParseUnitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn parses_compound_units() {
+        let unit: Unit = "kg*m/s^2".parse().unwrap();
+        assert_eq!(
+            unit.dimension().unwrap(),
+            Dimension::new([1, 1, -2, 0, 0, 0, 0])
+        );
+        let unit: Unit = "J/kg/K".parse().unwrap();
+        assert_eq!(
+            unit.dimension().unwrap(),
+            Dimension::new([2, 0, -2, 0, -1, 0, 0])
+        );
+        assert_matches!("furlong".parse::<Unit>(), Err(ParseUnitError::UnknownUnit));
+    }
+
+    #[test]
+    fn parse_errs_on_power_overflowing_in_denominator() {
+        assert_matches!("m/s^-128".parse::<Unit>(), Err(ParseUnitError::InvalidPower));
+        assert_matches!("m/s^127".parse::<Unit>(), Ok(_));
+    }
+
+    #[test]
+    fn displays_negative_powers_as_denominator() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!("km/h".parse::<Unit>().unwrap().to_string(), "km/h");
+        assert_eq!(
+            Dimension::new([1, 1, -2, 0, 0, 0, 0]).to_string(),
+            "m*kg/s^2"
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use crate::number::{
+    ApproximateConvert, Complex, Number, NumberConvert, NumberError, Operands, ParseRationalError,
+    Rational, RoundingConvert, RoundingMode,
+};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Stack item of any built-in number type, operands being promoted to the more general type
+/// of the two before any arithmetic: integer, then rational, then float, then complex.
+/// Integers that do not divide evenly yield a rational when `EXACT` and a float otherwise
+#[derive(Clone, Copy, Debug)]
+pub enum Value<const EXACT: bool = true> {
+    Integer(i64),
+    Rational(Rational<i64>),
+    Float(f64),
+    Complex(Complex<f64>),
+}
+
+type Arithmetic<T> = fn(T, T) -> Result<T, OperationError>;
+
+/// Two values promoted to the same type
+enum Promoted {
+    Integer(i64, i64),
+    Rational(Rational<i64>, Rational<i64>),
+    Float(f64, f64),
+    Complex(Complex<f64>, Complex<f64>),
+}
+
+impl<const EXACT: bool> Value<EXACT> {
+    fn rank(&self) -> u8 {
+        match self {
+            Value::Integer(_) => 0,
+            Value::Rational(_) => 1,
+            Value::Float(_) => 2,
+            Value::Complex(_) => 3,
+        }
+    }
+
+    fn to_rational(self) -> Rational<i64> {
+        match self {
+            Value::Integer(i) => i.into(),
+            Value::Rational(r) => r,
+            _ => unreachable!("only integers are promoted to rationals"),
+        }
+    }
+
+    fn to_float(self) -> f64 {
+        match self {
+            Value::Integer(i) => i as f64,
+            Value::Rational(r) => *r.numerator() as f64 / *r.denominator() as f64,
+            Value::Float(f) => f,
+            Value::Complex(_) => unreachable!("complex numbers are never demoted to floats"),
+        }
+    }
+
+    fn to_complex(self) -> Complex<f64> {
+        match self {
+            Value::Complex(c) => c,
+            value => value.to_float().into(),
+        }
+    }
+
+    fn promote(self, other: Self) -> Promoted {
+        match self.rank().max(other.rank()) {
+            0 => match (self, other) {
+                (Value::Integer(a), Value::Integer(b)) => Promoted::Integer(a, b),
+                _ => unreachable!("only integers have the lowest rank"),
+            },
+            1 => Promoted::Rational(self.to_rational(), other.to_rational()),
+            2 => Promoted::Float(self.to_float(), other.to_float()),
+            _ => Promoted::Complex(self.to_complex(), other.to_complex()),
+        }
+    }
+
+    /// Fractions that turn out whole are kept as integers
+    fn rational(value: Rational<i64>) -> Self {
+        if *value.denominator() == 1 {
+            Value::Integer(*value.numerator())
+        } else {
+            Value::Rational(value)
+        }
+    }
+
+    fn apply(
+        self,
+        other: Self,
+        integer: Arithmetic<i64>,
+        rational: Arithmetic<Rational<i64>>,
+        float: Arithmetic<f64>,
+        complex: Arithmetic<Complex<f64>>,
+    ) -> Result<Self, OperationError> {
+        match self.promote(other) {
+            Promoted::Integer(a, b) => integer(a, b).map(Value::Integer),
+            Promoted::Rational(a, b) => rational(a, b).map(Value::rational),
+            Promoted::Float(a, b) => float(a, b).map(Value::Float),
+            Promoted::Complex(a, b) => complex(a, b).map(Value::Complex),
+        }
+    }
+}
+
+impl<const EXACT: bool> From<i64> for Value<EXACT> {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl<const EXACT: bool> From<Rational<i64>> for Value<EXACT> {
+    fn from(value: Rational<i64>) -> Self {
+        Value::rational(value)
+    }
+}
+
+impl<const EXACT: bool> From<f64> for Value<EXACT> {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl<const EXACT: bool> From<Complex<f64>> for Value<EXACT> {
+    fn from(value: Complex<f64>) -> Self {
+        Value::Complex(value)
+    }
+}
+
+/// Every number type a value can hold converts into it
+impl<T, const EXACT: bool> NumberConvert<Value<EXACT>> for T
+where
+    Value<EXACT>: From<T>,
+{
+    fn convert(self) -> Result<Value<EXACT>, OperationError> {
+        Ok(self.into())
+    }
+}
+
+impl<const EXACT: bool> NumberConvert<f64> for Value<EXACT> {
+    /// Complex numbers only convert when they are real
+    fn convert(self) -> Result<f64, OperationError> {
+        match self {
+            Value::Complex(c) if !c.is_real() => {
+                let operands = Operands::new(format_args!("{c} as f64"));
+                Err(NumberError::DomainError(operands).into())
+            }
+            Value::Complex(c) => Ok(*c.real()),
+            value => Ok(value.to_float()),
+        }
+    }
+}
+
+impl<const EXACT: bool> NumberConvert<i64> for Value<EXACT> {
+    fn convert(self) -> Result<i64, OperationError> {
+        match self {
+            Value::Integer(i) => Ok(i),
+            Value::Rational(r) => {
+                let operands = Operands::new(format_args!("{r} as i64"));
+                Err(NumberError::InexactResult(operands).into())
+            }
+            value => NumberConvert::<f64>::convert(value)?.convert(),
+        }
+    }
+}
+
+/// Narrower integers are held as `i64`, into which they convert back after a range check
+macro_rules! impl_narrow_convert_for_value {
+    ($($T:ty),*) => {
+        $(
+            impl<const EXACT: bool> From<$T> for Value<EXACT> {
+                fn from(value: $T) -> Self {
+                    Value::Integer(value.into())
+                }
+            }
+
+            impl<const EXACT: bool> NumberConvert<$T> for Value<EXACT> {
+                fn convert(self) -> Result<$T, OperationError> {
+                    NumberConvert::<i64>::convert(self)?.convert()
+                }
+            }
+        )*
+    };
+}
+
+impl_narrow_convert_for_value!(i8, i16, i32, u8, u16, u32);
+
+impl<const EXACT: bool> RoundingConvert<i64> for Value<EXACT> {
+    /// Rationals are rounded exactly, from their quotient and remainder
+    fn convert_rounding(self, rounding: RoundingMode) -> Result<i64, OperationError> {
+        let r = match self {
+            Value::Integer(i) => return Ok(i),
+            Value::Rational(r) => r,
+            value => return NumberConvert::<f64>::convert(value)?.convert_rounding(rounding),
+        };
+        let (numerator, denominator) = (*r.numerator(), *r.denominator());
+        let (floor, rest) = (
+            numerator.div_euclid(denominator),
+            numerator.rem_euclid(denominator),
+        );
+        let up = match rounding {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => rest != 0,
+            RoundingMode::Round => {
+                rest > denominator - rest || (rest == denominator - rest && numerator > 0)
+            }
+            RoundingMode::Trunc => numerator < 0 && rest != 0,
+        };
+        Ok(floor + i64::from(up))
+    }
+}
+
+impl<const EXACT: bool> ApproximateConvert<i64> for Value<EXACT> {
+    fn convert_approximate(self, max_denominator: i64) -> Result<Rational<i64>, OperationError> {
+        match self {
+            Value::Integer(i) if max_denominator >= 1 => Ok(i.into()),
+            Value::Rational(r) if *r.denominator() <= max_denominator => Ok(r),
+            value => NumberConvert::<f64>::convert(value)?.convert_approximate(max_denominator),
+        }
+    }
+}
+
+impl<const EXACT: bool> Number for Value<EXACT> {
+    const ZERO: Self = Value::Integer(0);
+    const ONE: Self = Value::Integer(1);
+    const TEN: Self = Value::Integer(10);
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        self.apply(other, i64::add, Rational::add, f64::add, Complex::add)
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        self.apply(
+            other,
+            i64::subtract,
+            Rational::subtract,
+            f64::subtract,
+            Complex::subtract,
+        )
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        self.apply(
+            other,
+            i64::multiply,
+            Rational::multiply,
+            f64::multiply,
+            Complex::multiply,
+        )
+    }
+
+    /// Integers dividing evenly stay integers, others become rationals or floats depending on `EXACT`
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        match self.promote(other) {
+            Promoted::Integer(a, b) if a.remainder(b)? == 0 => a.divide(b).map(Value::Integer),
+            Promoted::Integer(a, b) if EXACT => Rational::new(a, b).map(Value::Rational),
+            Promoted::Integer(a, b) => (a as f64).divide(b as f64).map(Value::Float),
+            Promoted::Rational(a, b) => a.divide(b).map(Value::rational),
+            Promoted::Float(a, b) => a.divide(b).map(Value::Float),
+            Promoted::Complex(a, b) => a.divide(b).map(Value::Complex),
+        }
+    }
+
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        self.apply(
+            other,
+            i64::remainder,
+            Rational::remainder,
+            f64::remainder,
+            Complex::remainder,
+        )
+    }
+
+    fn abs(self) -> Result<Self, OperationError> {
+        match self {
+            Value::Integer(i) => Number::abs(i).map(Value::Integer),
+            Value::Rational(r) => r.abs().map(Value::Rational),
+            Value::Float(x) => Number::abs(x).map(Value::Float),
+            Value::Complex(c) => c.abs().map(Value::Complex),
+        }
+    }
+}
+
+impl<const EXACT: bool> PartialEq for Value<EXACT> {
+    /// Values are compared after promotion, so that `1 == 1.0`
+    fn eq(&self, other: &Self) -> bool {
+        match self.promote(*other) {
+            Promoted::Integer(a, b) => a == b,
+            Promoted::Rational(a, b) => a == b,
+            Promoted::Float(a, b) => a == b,
+            Promoted::Complex(a, b) => a == b,
+        }
+    }
+}
+
+impl<const EXACT: bool> PartialOrd for Value<EXACT> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.promote(*other) {
+            Promoted::Integer(a, b) => a.partial_cmp(&b),
+            Promoted::Rational(a, b) => a.partial_cmp(&b),
+            Promoted::Float(a, b) => a.partial_cmp(&b),
+            Promoted::Complex(a, b) => a.partial_cmp(&b),
+        }
+    }
+}
+
+impl<const EXACT: bool> Display for Value<EXACT> {
+    /// Floats always show a fractional part or exponent, so they can be told apart from integers
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Value::Integer(i) => i.fmt(f),
+            Value::Rational(r) => r.fmt(f),
+            Value::Float(x) => f.write_fmt(format_args!("{x:?}")),
+            Value::Complex(c) => c.fmt(f),
+        }
+    }
+}
+
+impl<const EXACT: bool> FromStr for Value<EXACT> {
+    type Err = ParseValueError;
+
+    /// Infers the type from the literal: `3` is an integer, `3/4` a rational,
+    /// `3.5` or `1e3` a float and `3+4i` a complex number
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(i) = s.parse() {
+            Ok(Value::Integer(i))
+        } else if s.contains('/') {
+            s.parse()
+                .map(Value::rational)
+                .map_err(ParseValueError::Rational)
+        } else if let Ok(x) = s.parse() {
+            Ok(Value::Float(x))
+        } else {
+            s.parse()
+                .map(Value::Complex)
+                .map_err(|_| ParseValueError::Unknown)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseValueError {
+    Rational(ParseRationalError),
+    Unknown,
+}
+
+impl Display for ParseValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseValueError::Rational(ref e) => e.fmt(f),
+            ParseValueError::Unknown => f.write_str("Invalid number"),
+        }
+    }
+}
+
+impl Error for ParseValueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseValueError::Rational(ref e) => Some(e),
+            ParseValueError::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn add_promotes_integer_to_float() {
+        let value = Value::<true>::Integer(1).add(Value::Float(0.5)).unwrap();
+        assert_matches!(value, Value::Float(1.5));
+    }
+
+    #[test]
+    fn divide_yields_rational_when_exact() {
+        let value = Value::<true>::Integer(1).divide(Value::Integer(4)).unwrap();
+        assert_eq!(value, Value::Rational(Rational::new(1, 4).unwrap()));
+    }
+
+    #[test]
+    fn divide_yields_float_when_inexact() {
+        let value = Value::<false>::Integer(1)
+            .divide(Value::Integer(4))
+            .unwrap();
+        assert_matches!(value, Value::Float(0.25));
+    }
+
+    #[test]
+    fn divide_keeps_integers_dividing_evenly() {
+        let value = Value::<true>::Integer(8).divide(Value::Integer(4)).unwrap();
+        assert_matches!(value, Value::Integer(2));
+        let result = Value::<true>::Integer(8).divide(Value::Integer(0));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn whole_rationals_become_integers() {
+        let half = Value::<true>::from(Rational::new(1, 2).unwrap());
+        assert_matches!(half.add(half).unwrap(), Value::Integer(1));
+    }
+
+    #[test]
+    fn rounds_rationals_exactly() {
+        let round = |numerator, denominator, rounding| {
+            let value = Value::<true>::from(Rational::new(numerator, denominator).unwrap());
+            value.convert_rounding(rounding).unwrap()
+        };
+        assert_eq!(round(7, 2, RoundingMode::Floor), 3);
+        assert_eq!(round(7, 2, RoundingMode::Round), 4);
+        assert_eq!(round(-7, 2, RoundingMode::Round), -4);
+        assert_eq!(round(-7, 2, RoundingMode::Ceil), -3);
+        assert_eq!(round(-7, 3, RoundingMode::Trunc), -2);
+        assert_eq!(round(-7, 3, RoundingMode::Floor), -3);
+    }
+
+    #[test]
+    fn converts_only_real_complex_numbers_to_floats() {
+        let real = Value::<true>::Complex(Complex::from(2.));
+        assert_matches!(NumberConvert::<f64>::convert(real), Ok(2.));
+        let complex = "1+2i".parse::<Value>().unwrap();
+        assert_matches!(
+            NumberConvert::<f64>::convert(complex),
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn parses_according_to_literal_syntax() {
+        let parse = |s: &str| s.parse::<Value>();
+        assert_matches!(parse("3"), Ok(Value::Integer(3)));
+        assert_matches!(parse("3/4"), Ok(Value::Rational(_)));
+        assert_matches!(parse("4/2"), Ok(Value::Integer(2)));
+        assert_matches!(parse("2.5"), Ok(Value::Float(2.5)));
+        assert_matches!(parse("1+2i"), Ok(Value::Complex(_)));
+        assert_matches!(parse("x"), Err(ParseValueError::Unknown));
+    }
+
+    #[test]
+    fn displays_floats_distinctly_from_integers() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        assert_eq!(Value::<true>::Float(2.).to_string(), "2.0");
+        assert_eq!(Value::<true>::Integer(2).to_string(), "2");
+    }
+}
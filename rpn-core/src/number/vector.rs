@@ -0,0 +1,134 @@
+use crate::number::{Float, Number};
+use crate::operation::OperationError;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+
+/// Two dimensional XY coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec2<N> {
+    pub x: N,
+    pub y: N,
+}
+
+/// Three dimensional XYZ coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3<N> {
+    pub x: N,
+    pub y: N,
+    pub z: N,
+}
+
+macro_rules! component_wise {
+    ($method:ident, $first:ident $(, $c:ident)*) => {
+        fn $method(self, other: Self) -> Result<Self, OperationError> {
+            Ok(Self {
+                $first: self.$first.$method(other.$first)?,
+                $($c: self.$c.$method(other.$c)?,)*
+            })
+        }
+    };
+}
+
+macro_rules! impl_vector {
+    ($V:ident, $D:literal, $first:ident $(, $c:ident)*) => {
+        impl<N: Number> $V<N> {
+            pub const fn new($first: N $(, $c: N)*) -> Self {
+                Self { $first $(, $c)* }
+            }
+
+            pub fn components(self) -> [N; $D] {
+                [self.$first $(, self.$c)*]
+            }
+
+            /// Multiplies every component by a scalar
+            pub fn scale(self, factor: N) -> Result<Self, OperationError> {
+                Ok(Self {
+                    $first: self.$first.multiply(factor.clone())?,
+                    $($c: self.$c.multiply(factor.clone())?,)*
+                })
+            }
+
+            /// Divides every component by a scalar
+            pub fn shrink(self, divisor: N) -> Result<Self, OperationError> {
+                Ok(Self {
+                    $first: self.$first.divide(divisor.clone())?,
+                    $($c: self.$c.divide(divisor.clone())?,)*
+                })
+            }
+
+            pub fn dot(self, other: Self) -> Result<N, OperationError> {
+                let product = self.$first.multiply(other.$first)?;
+                $(let product = product.add(self.$c.multiply(other.$c)?)?;)*
+                Ok(product)
+            }
+        }
+
+        /// Arithmetic is component-wise, use `scale`, `dot` or `cross` for vector products
+        impl<N: Number> Number for $V<N> {
+            const ZERO: Self = Self { $first: N::ZERO $(, $c: N::ZERO)* };
+            const ONE: Self = Self { $first: N::ONE $(, $c: N::ONE)* };
+            const TEN: Self = Self { $first: N::TEN $(, $c: N::TEN)* };
+
+            component_wise!(add, $first $(, $c)*);
+            component_wise!(subtract, $first $(, $c)*);
+            component_wise!(multiply, $first $(, $c)*);
+            component_wise!(divide, $first $(, $c)*);
+            component_wise!(remainder, $first $(, $c)*);
+
+            fn abs(self) -> Result<Self, OperationError> {
+                Ok(Self {
+                    $first: self.$first.abs()?,
+                    $($c: self.$c.abs()?,)*
+                })
+            }
+        }
+
+        impl<N: Number> PartialOrd for $V<N> {
+            /// A vector is only less than another when none of its components is greater
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                let ordering = self.$first.partial_cmp(&other.$first)?;
+                $(let ordering = match (ordering, self.$c.partial_cmp(&other.$c)?) {
+                    (ordering, Ordering::Equal) | (Ordering::Equal, ordering) => ordering,
+                    (first, second) if first == second => first,
+                    _ => return None,
+                };)*
+                Some(ordering)
+            }
+        }
+
+        impl<F: Float> $V<F> {
+            /// Euclidean length
+            pub fn norm(self) -> F {
+                let norm = self.$first.hypot(F::ZERO);
+                $(let norm = norm.hypot(self.$c);)*
+                norm
+            }
+        }
+
+        impl<N: Display> Display for $V<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.write_fmt(format_args!("({}", self.$first))?;
+                $(f.write_fmt(format_args!(", {}", self.$c))?;)*
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+impl_vector!(Vec2, 2, x, y);
+impl_vector!(Vec3, 3, x, y, z);
+
+impl<N: Number> Vec3<N> {
+    pub fn cross(self, other: Self) -> Result<Self, OperationError> {
+        let product = |a: &N, b: &N, c: &N, d: &N| {
+            a.clone()
+                .multiply(b.clone())?
+                .subtract(c.clone().multiply(d.clone())?)
+        };
+        Ok(Self::new(
+            product(&self.y, &other.z, &self.z, &other.y)?,
+            product(&self.z, &other.x, &self.x, &other.z)?,
+            product(&self.x, &other.y, &self.y, &other.x)?,
+        ))
+    }
+}
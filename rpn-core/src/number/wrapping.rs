@@ -0,0 +1,178 @@
+use crate::number::{Bounded, Integer, Number, NumberError, Power};
+use crate::operation::OperationError;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Integer whose arithmetic wraps around at the bounds of its type, as machine integers do
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping<T>(pub T);
+
+macro_rules! impl_number_for_wrapping {
+    ($($T:ty),*) => {
+        $(
+            impl Number for Wrapping<$T> {
+                const ZERO: Self = Self(0);
+                const ONE: Self = Self(1);
+                const TEN: Self = Self(10);
+
+                fn add(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.wrapping_add(other.0)))
+                }
+
+                fn subtract(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.wrapping_sub(other.0)))
+                }
+
+                fn multiply(self, other: Self) -> Result<Self, OperationError> {
+                    Ok(Self(self.0.wrapping_mul(other.0)))
+                }
+
+                /// `MIN / -1` wraps around to `MIN`
+                fn divide(self, other: Self) -> Result<Self, OperationError> {
+                    if other.0 == 0 {
+                        return Err(NumberError::DivisionByZero.into());
+                    }
+                    Ok(Self(self.0.wrapping_div(other.0)))
+                }
+
+                fn remainder(self, other: Self) -> Result<Self, OperationError> {
+                    if other.0 == 0 {
+                        return Err(NumberError::DivisionByZero.into());
+                    }
+                    Ok(Self(self.0.wrapping_rem(other.0)))
+                }
+            }
+
+            impl Bounded for Wrapping<$T> {
+                const MIN: Self = Self(<$T>::MIN);
+                const MAX: Self = Self(<$T>::MAX);
+            }
+
+            impl Power for Wrapping<$T> {
+                /// Negative exponents are left to checked powers, which never overflow on them,
+                /// while the others are raised by repeated squaring to handle any exponent
+                fn power(self, exponent: Self) -> Result<Self, OperationError> {
+                    if exponent < Self::ZERO {
+                        return self.0.power(exponent.0).map(Self);
+                    }
+                    let (mut base, mut exponent, mut result) = (self.0, exponent.0, Self::ONE.0);
+                    while exponent > 0 {
+                        if exponent & 1 == 1 {
+                            result = result.wrapping_mul(base);
+                        }
+                        base = base.wrapping_mul(base);
+                        exponent >>= 1;
+                    }
+                    Ok(Self(result))
+                }
+
+                fn nth_root(self, n: Self) -> Result<Self, OperationError> {
+                    self.0.nth_root(n.0).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_wrapping!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Bits are the same as those of the underlying integer
+impl<T: Integer> Integer for Wrapping<T>
+where
+    Self: Bounded,
+{
+    fn and(self, other: Self) -> Self {
+        Self(self.0.and(other.0))
+    }
+
+    fn or(self, other: Self) -> Self {
+        Self(self.0.or(other.0))
+    }
+
+    fn xor(self, other: Self) -> Self {
+        Self(self.0.xor(other.0))
+    }
+
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+
+    fn shift_left(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.shift_left(amount.0).map(Self)
+    }
+
+    fn shift_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.shift_right(amount.0).map(Self)
+    }
+
+    fn arithmetic_shift_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.arithmetic_shift_right(amount.0).map(Self)
+    }
+
+    fn rotate_bits_left(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.rotate_bits_left(amount.0).map(Self)
+    }
+
+    fn rotate_bits_right(self, amount: Self) -> Result<Self, OperationError> {
+        self.0.rotate_bits_right(amount.0).map(Self)
+    }
+
+    fn count_ones(self) -> Self {
+        Self(self.0.count_ones())
+    }
+
+    fn leading_zeros(self) -> Self {
+        Self(self.0.leading_zeros())
+    }
+
+    fn trailing_zeros(self) -> Self {
+        Self(self.0.trailing_zeros())
+    }
+}
+
+impl<T: Display> Display for Wrapping<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: FromStr> FromStr for Wrapping<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn arithmetic_wraps_around() {
+        assert_eq!(Wrapping(250u8).add(Wrapping(10)).unwrap(), Wrapping(4));
+        assert_eq!(
+            Wrapping(0u32).subtract(Wrapping(1)).unwrap(),
+            Wrapping(u32::MAX)
+        );
+        assert_eq!(
+            Wrapping(i8::MIN).divide(Wrapping(-1)).unwrap(),
+            Wrapping(i8::MIN)
+        );
+        assert_eq!(Wrapping(3u8).power(Wrapping(6)).unwrap(), Wrapping(217));
+        let power = Wrapping(2u64).power(Wrapping(1 << 33)).unwrap();
+        assert_eq!(power, Wrapping(0));
+        let power = Wrapping(-1i64).power(Wrapping((1 << 33) + 1)).unwrap();
+        assert_eq!(power, Wrapping(-1));
+    }
+
+    #[test]
+    fn divide_errs_on_zero() {
+        let result = Wrapping(1u8).divide(Wrapping(0));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+}
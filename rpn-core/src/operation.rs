@@ -1,30 +1,136 @@
-use crate::number::NumberError;
+use crate::number::{AngleMode, Number, NumberError};
+use crate::register::RegisterError;
 use crate::stack::{Stack, StackError};
 use core::error::Error;
 use core::fmt::{Display, Formatter};
 
+/// Operation replacing the float on top of the stack by the result of one of its `Float` functions
+macro_rules! float_operation {
+    ($(#[$doc:meta] $name:ident),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: Float,
+            {
+                stack.pop()?.map(Float::$name)?.push()
+            }
+        )*
+    };
+}
+
+mod abs;
 mod add;
+mod argument;
+mod bit_count;
+mod bitwise;
+mod clamp;
+mod comparison;
+mod conjugate;
+mod convert;
 mod copy;
+mod cross;
+mod depth;
 mod divide;
+mod dot;
+mod drop;
+mod duplicate;
+mod exponential;
+mod hyperbolic;
+mod inverse;
+mod make_complex;
+mod modular_power;
+mod modulus;
 mod multiply;
+mod narrow;
+mod negate;
+mod norm;
+mod pack2;
+mod pack3;
+mod power;
+mod reduce;
 mod remainder;
+mod roll;
+mod root;
 mod rotate;
+mod round;
+mod scale;
+mod shift;
+mod sign;
+mod split_complex;
+mod sqrt;
 mod square;
 mod subtract;
+mod to_degrees;
+mod to_float;
+mod to_radians;
+mod to_rational;
+mod trigonometric;
+mod unpack;
 
+pub use abs::abs;
 pub use add::add;
+pub use argument::argument;
+pub use bit_count::{count_ones, leading_zeros, trailing_zeros};
+pub use bitwise::{and, not, or, xor};
+pub use clamp::clamp;
+pub use comparison::{compare, max, min};
+pub use conjugate::conjugate;
+pub use convert::convert;
 pub use copy::copy;
+pub use cross::cross;
+pub use depth::depth;
 pub use divide::divide;
+pub use dot::dot;
+pub use drop::{clear, drop, dropn, nip};
+pub use duplicate::{dupn, over, pick, tuck};
+pub use exponential::{exp, ln, log2, log10};
+pub use hyperbolic::{acosh, asinh, atanh, cosh, sinh, tanh};
+pub use inverse::inverse;
+pub use make_complex::make_complex;
+pub use modular_power::modular_power;
+pub use modulus::modulus;
 pub use multiply::multiply;
+pub use narrow::narrow;
+pub use negate::negate;
+pub use norm::norm;
+pub use pack2::pack2;
+pub use pack3::pack3;
+pub use power::power;
+pub use reduce::{
+    maximum, maximum_n, mean, mean_n, minimum, minimum_n, product, product_n, sum, sum_n,
+};
 pub use remainder::remainder;
+pub use roll::{roll, roll_down, rot3};
+pub use root::root;
 pub use rotate::rotate;
+pub use round::{ceil, floor, round, truncate};
+pub use scale::scale;
+pub use shift::{
+    arithmetic_shift_right, rotate_bits_left, rotate_bits_right, shift_left, shift_right,
+};
+pub use sign::sign;
+pub use split_complex::split_complex;
+pub use sqrt::sqrt;
 pub use square::square;
 pub use subtract::subtract;
+pub use to_degrees::to_degrees;
+pub use to_float::to_float;
+pub use to_radians::to_radians;
+pub use to_rational::to_rational;
+pub use trigonometric::{acos, asin, atan, cos, sin, tan};
+pub use unpack::unpack;
 
 #[derive(Debug)]
 pub enum OperationError {
     Stack(StackError),
     Number(NumberError),
+    Register(RegisterError),
+    /// The item is not of a kind the operation can work with
+    InvalidOperand,
+    /// Quantities of different physical dimensions cannot be added, subtracted or converted
+    IncompatibleDimensions,
 }
 
 impl From<StackError> for OperationError {
@@ -33,6 +139,12 @@ impl From<StackError> for OperationError {
     }
 }
 
+impl From<RegisterError> for OperationError {
+    fn from(value: RegisterError) -> Self {
+        Self::Register(value)
+    }
+}
+
 impl From<NumberError> for OperationError {
     fn from(value: NumberError) -> Self {
         Self::Number(value)
@@ -44,6 +156,9 @@ impl Display for OperationError {
         match *self {
             OperationError::Stack(ref e) => f.write_fmt(format_args!("Stack error: {e}")),
             OperationError::Number(ref e) => f.write_fmt(format_args!("Number error: {e}")),
+            OperationError::Register(ref e) => f.write_fmt(format_args!("Register error: {e}")),
+            OperationError::InvalidOperand => f.write_str("Invalid operand"),
+            OperationError::IncompatibleDimensions => f.write_str("Incompatible dimensions"),
         }
     }
 }
@@ -53,14 +168,27 @@ impl Error for OperationError {
         match *self {
             OperationError::Stack(ref e) => Some(e),
             OperationError::Number(ref e) => Some(e),
+            OperationError::Register(ref e) => Some(e),
+            OperationError::InvalidOperand | OperationError::IncompatibleDimensions => None,
         }
     }
 }
 
+/// Settings operations are evaluated with
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Context {
+    /// Unit angles of trigonometric operations are in
+    pub angle_mode: AngleMode,
+}
+
+/// Most items [`OperationStack::reorder`] moves without copying the whole stack
+const REORDER_ITEMS: usize = 16;
+
 #[derive(Debug)]
 pub struct OperationStack<S: Stack, T> {
     stack: S,
     state: T,
+    context: Context,
 }
 
 pub type OpStack<S> = OperationStack<S, NoItems>;
@@ -69,20 +197,33 @@ impl<S: Stack, T> OperationStack<S, T> {
     pub(crate) fn stack(self) -> S {
         self.stack
     }
+
+    pub fn context(&self) -> Context {
+        self.context
+    }
 }
 
 #[derive(Debug)]
 pub struct NoItems;
 #[derive(Debug)]
 pub struct OneItem<T>(T);
+/// Two held items, the first one being the one popped first; they may be of different types
+/// once converted from stack items, as long as they are converted back before being pushed
+#[derive(Debug)]
+pub struct TwoItems<T, U = T>(T, U);
+/// Three held items, in the order they were popped, which may be of different types
+#[derive(Debug)]
+pub struct ThreeItems<T, U = T, V = U>(T, U, V);
+/// Four held items, in the order they were popped, which may be of different types
 #[derive(Debug)]
-pub struct TwoItems<T>(T, T);
+pub struct FourItems<T, U = T, V = U, W = V>(T, U, V, W);
 
 impl<S: Stack> OperationStack<S, NoItems> {
-    pub fn new(stack: S) -> Self {
+    pub fn new(stack: S, context: Context) -> Self {
         Self {
             stack,
             state: NoItems,
+            context,
         }
     }
 
@@ -90,65 +231,341 @@ impl<S: Stack> OperationStack<S, NoItems> {
         let item = self.stack.pop()?;
         Ok(OperationStack {
             stack: self.stack,
+            context: self.context,
             state: OneItem(item),
         })
     }
+
+    /// Holds an item that was not popped, such as one computed from the stack, to be pushed
+    pub fn hold<T>(self, item: T) -> OperationStack<S, OneItem<T>> {
+        OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: OneItem(item),
+        }
+    }
+
+    /// Number of items on the stack
+    pub fn depth(&self) -> usize {
+        self.stack.size()
+    }
+
+    /// Pops the number of items the rest of the operation works on, which must be a whole
+    /// number no larger than the number of items left
+    pub fn pop_count(mut self) -> Result<(Self, usize), OperationError>
+    where
+        S::Item: Number,
+    {
+        let n = self.stack.pop()?;
+        let depth = self.stack.size();
+        // Counting up to the number works for every number type, and stops past the depth
+        let (mut value, mut count) = (S::Item::ZERO, 0);
+        while value < n && count <= depth {
+            (value, count) = (value.add(S::Item::ONE)?, count + 1);
+        }
+        if count > depth {
+            Err(StackError::TooShallow(depth).into())
+        } else if value != n {
+            Err(OperationError::InvalidOperand)
+        } else {
+            Ok((self, count))
+        }
+    }
+
+    /// Pops the top `count` items, holding what they reduce to with `f` as by [`Stack::reduce`]
+    pub fn reduce(
+        self,
+        count: usize,
+        f: impl FnMut(S::Item, S::Item) -> Result<S::Item, OperationError>,
+    ) -> Result<OperationStack<S, OneItem<S::Item>>, OperationError> {
+        let item = self.stack.reduce(count, f)?;
+        Ok(self.reorder(count, [])?.hold(item))
+    }
+
+    /// Replaces the top `count` items of the stack by the ones picked by `order`, indexed from
+    /// the deepest of those items, the last one picked ending up on top of the stack
+    pub fn reorder(
+        mut self,
+        count: usize,
+        order: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, OperationError> {
+        let depth = self.stack.size();
+        match depth {
+            0 if count > 0 => return Err(StackError::Empty.into()),
+            depth if count > depth => return Err(StackError::TooShallow(depth).into()),
+            _ => {}
+        }
+        // Items picked in their own place stay on the stack, only the ones above are popped
+        let mut order = order.into_iter().peekable();
+        let mut kept = 0;
+        while kept < count && order.next_if_eq(&kept).is_some() {
+            kept += 1;
+        }
+        let moved = count - kept;
+        let copy = (moved > REORDER_ITEMS).then(|| self.stack.clone());
+        let mut popped: [Option<S::Item>; REORDER_ITEMS] = core::array::from_fn(|_| None);
+        for slot in 0..moved {
+            let item = self.stack.pop()?;
+            if let Some(slot) = popped.get_mut(slot) {
+                *slot = Some(item);
+            }
+        }
+        for index in order {
+            let item = match (index.checked_sub(kept), &copy) {
+                (None, _) => self.stack.iter().nth(depth - count + index).cloned(),
+                (Some(_), Some(copy)) => copy.iter().nth(depth - count + index).cloned(),
+                (Some(_), None) => count
+                    .checked_sub(index + 1)
+                    .and_then(|slot| popped[slot].clone()),
+            };
+            self.stack.push(item.ok_or(StackError::Empty)?)?;
+        }
+        Ok(self)
+    }
 }
 
-impl<S: Stack> OperationStack<S, OneItem<S::Item>> {
-    pub fn pop(mut self) -> Result<OperationStack<S, TwoItems<S::Item>>, OperationError> {
+impl<S: Stack, T> OperationStack<S, OneItem<T>> {
+    pub fn pop(mut self) -> Result<OperationStack<S, TwoItems<T, S::Item>>, OperationError> {
         let item = self.stack.pop()?;
         Ok(OperationStack {
             stack: self.stack,
+            context: self.context,
             state: TwoItems(self.state.0, item),
         })
     }
 
+    pub fn map<R>(
+        self,
+        f: impl FnOnce(T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, OneItem<R>>, OperationError> {
+        let item = f(self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: OneItem(item),
+        })
+    }
+
+    /// Hands the held item over to `f`, such as to store it outside of the stack
+    pub fn consume(
+        self,
+        f: impl FnOnce(T) -> Result<(), OperationError>,
+    ) -> Result<OperationStack<S, NoItems>, OperationError> {
+        f(self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: NoItems,
+        })
+    }
+
+    /// Splits the held item in two, the second one ending up on top of the stack once pushed
+    pub fn split<A, B>(
+        self,
+        f: impl FnOnce(T) -> Result<(A, B), OperationError>,
+    ) -> Result<OperationStack<S, TwoItems<B, A>>, OperationError> {
+        let (first, second) = f(self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: TwoItems(second, first),
+        })
+    }
+}
+
+impl<S: Stack, T: Clone> OperationStack<S, OneItem<T>> {
+    pub fn copy(self) -> Result<OperationStack<S, TwoItems<T>>, OperationError> {
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: TwoItems(self.state.0.clone(), self.state.0),
+        })
+    }
+}
+
+impl<S: Stack> OperationStack<S, OneItem<S::Item>> {
     pub fn push(mut self) -> Result<OperationStack<S, NoItems>, OperationError> {
         self.stack.push(self.state.0)?;
         Ok(OperationStack {
             stack: self.stack,
+            context: self.context,
             state: NoItems,
         })
     }
 }
 
-impl<S: Stack> OperationStack<S, OneItem<S::Item>>
-where
-    S::Item: Copy,
-{
-    pub fn copy(self) -> Result<OperationStack<S, TwoItems<S::Item>>, OperationError> {
+impl<S: Stack, I: IntoIterator<Item = S::Item>> OperationStack<S, OneItem<I>> {
+    /// Pushes every held item in order, the last one ending up on top of the stack
+    pub fn push_all(mut self) -> Result<OperationStack<S, NoItems>, OperationError> {
+        for item in self.state.0 {
+            self.stack.push(item)?;
+        }
         Ok(OperationStack {
             stack: self.stack,
-            state: TwoItems(self.state.0, self.state.0),
+            context: self.context,
+            state: NoItems,
         })
     }
 }
 
-impl<S: Stack> OperationStack<S, TwoItems<S::Item>> {
-    pub fn push(mut self) -> Result<OperationStack<S, OneItem<S::Item>>, OperationError> {
+impl<S: Stack, T, U> OperationStack<S, TwoItems<T, U>> {
+    pub fn combine<R>(
+        self,
+        f: impl FnOnce(U, T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, OneItem<R>>, OperationError> {
+        let item = f(self.state.1, self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: OneItem(item),
+        })
+    }
+
+    pub fn rotate(self) -> Result<OperationStack<S, TwoItems<U, T>>, OperationError> {
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: TwoItems(self.state.1, self.state.0),
+        })
+    }
+}
+
+impl<S: Stack, T, U> OperationStack<S, TwoItems<T, U>> {
+    #[allow(clippy::type_complexity)]
+    pub fn pop(mut self) -> Result<OperationStack<S, ThreeItems<T, U, S::Item>>, OperationError> {
+        let item = self.stack.pop()?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: ThreeItems(self.state.0, self.state.1, item),
+        })
+    }
+}
+
+impl<S: Stack, T> OperationStack<S, TwoItems<T, S::Item>> {
+    pub fn push(mut self) -> Result<OperationStack<S, OneItem<T>>, OperationError> {
         self.stack.push(self.state.1)?;
         Ok(OperationStack {
             stack: self.stack,
+            context: self.context,
             state: OneItem(self.state.0),
         })
     }
+}
+
+impl<S: Stack, T, U, V> OperationStack<S, ThreeItems<T, U, V>> {
+    #[allow(clippy::type_complexity)]
+    pub fn pop(mut self) -> Result<OperationStack<S, FourItems<T, U, V, S::Item>>, OperationError> {
+        let item = self.stack.pop()?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: FourItems(self.state.0, self.state.1, self.state.2, item),
+        })
+    }
 
-    pub fn combine(
+    /// Combines the three held items, given in the order they were on the stack
+    pub fn combine3<R>(
         self,
-        f: impl FnOnce(S::Item, S::Item) -> Result<S::Item, OperationError>,
-    ) -> Result<OperationStack<S, OneItem<S::Item>>, OperationError> {
+        f: impl FnOnce(V, U, T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, OneItem<R>>, OperationError> {
+        let item = f(self.state.2, self.state.1, self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: OneItem(item),
+        })
+    }
+
+    /// Combines the two items popped first, keeping the third one
+    pub fn combine<R>(
+        self,
+        f: impl FnOnce(U, T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, TwoItems<R, V>>, OperationError> {
         let item = f(self.state.1, self.state.0)?;
         Ok(OperationStack {
             stack: self.stack,
+            context: self.context,
+            state: TwoItems(item, self.state.2),
+        })
+    }
+
+    /// Swaps the two items popped first
+    pub fn rotate(self) -> Result<OperationStack<S, ThreeItems<U, T, V>>, OperationError> {
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: ThreeItems(self.state.1, self.state.0, self.state.2),
+        })
+    }
+
+    /// Moves the item popped last so that it ends up on top of the stack once pushed back,
+    /// as the `rot3` operation
+    pub fn rotate3(self) -> Result<OperationStack<S, ThreeItems<V, T, U>>, OperationError> {
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: ThreeItems(self.state.2, self.state.0, self.state.1),
+        })
+    }
+}
+
+impl<S: Stack, T, U> OperationStack<S, ThreeItems<T, U, S::Item>> {
+    pub fn push(mut self) -> Result<OperationStack<S, TwoItems<T, U>>, OperationError> {
+        self.stack.push(self.state.2)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: TwoItems(self.state.0, self.state.1),
+        })
+    }
+}
+
+impl<S: Stack, T, U, V, W> OperationStack<S, FourItems<T, U, V, W>> {
+    /// Combines the four held items, given in the order they were on the stack
+    pub fn combine4<R>(
+        self,
+        f: impl FnOnce(W, V, U, T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, OneItem<R>>, OperationError> {
+        let item = f(self.state.3, self.state.2, self.state.1, self.state.0)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
             state: OneItem(item),
         })
     }
 
-    pub fn rotate(self) -> Result<OperationStack<S, TwoItems<S::Item>>, OperationError> {
+    /// Combines the two items popped first, keeping the other two
+    pub fn combine<R>(
+        self,
+        f: impl FnOnce(U, T) -> Result<R, OperationError>,
+    ) -> Result<OperationStack<S, ThreeItems<R, V, W>>, OperationError> {
+        let item = f(self.state.1, self.state.0)?;
         Ok(OperationStack {
             stack: self.stack,
-            state: TwoItems(self.state.1, self.state.0),
+            context: self.context,
+            state: ThreeItems(item, self.state.2, self.state.3),
+        })
+    }
+
+    /// Swaps the two items popped first
+    #[allow(clippy::type_complexity)]
+    pub fn rotate(self) -> Result<OperationStack<S, FourItems<U, T, V, W>>, OperationError> {
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: FourItems(self.state.1, self.state.0, self.state.2, self.state.3),
+        })
+    }
+}
+
+impl<S: Stack, T, U, V> OperationStack<S, FourItems<T, U, V, S::Item>> {
+    pub fn push(mut self) -> Result<OperationStack<S, ThreeItems<T, U, V>>, OperationError> {
+        self.stack.push(self.state.3)?;
+        Ok(OperationStack {
+            stack: self.stack,
+            context: self.context,
+            state: ThreeItems(self.state.0, self.state.1, self.state.2),
         })
     }
 }
@@ -175,11 +592,24 @@ mod tests {
         }
     }
 
+    impl<T> ThreeItems<T> {
+        fn inspect(self) -> (T, T, T) {
+            (self.0, self.1, self.2)
+        }
+    }
+
+    impl<T> FourItems<T> {
+        fn inspect(self) -> (T, T, T, T) {
+            (self.0, self.1, self.2, self.3)
+        }
+    }
+
     #[test]
     fn no_item_stack_can_pop_item() {
         let no_items = OperationStack {
             stack: SmallStack::one_element(1),
             state: NoItems,
+            context: Context::default(),
         };
         let one_item = no_items.pop().unwrap();
         assert_matches!(one_item.state.inspect(), 1);
@@ -191,6 +621,7 @@ mod tests {
         let one_item = OperationStack {
             stack: SmallStack::one_element(1),
             state: OneItem(2),
+            context: Context::default(),
         };
         let two_items = one_item.pop().unwrap();
         assert_matches!(two_items.state.inspect(), (2, 1));
@@ -200,8 +631,9 @@ mod tests {
     #[test]
     fn one_item_stack_can_push_item() {
         let one_item = OperationStack {
-            stack: SmallStack::empty(),
+            stack: SmallStack::<i32>::empty(),
             state: OneItem(1),
+            context: Context::default(),
         };
         let no_items = one_item.push().unwrap();
         assert_matches!(no_items.state.inspect(), ());
@@ -211,19 +643,87 @@ mod tests {
     #[test]
     fn one_item_stack_can_copy_item() {
         let one_item = OperationStack {
-            stack: SmallStack::empty(),
+            stack: SmallStack::<i32>::empty(),
             state: OneItem(1),
+            context: Context::default(),
         };
         let two_items = one_item.copy().unwrap();
         assert_matches!(two_items.state.inspect(), (1, 1));
         assert_matches!(two_items.stack.inspect(), (None, None));
     }
 
+    #[test]
+    fn one_item_stack_can_map_item() {
+        let one_item = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: OneItem(1),
+            context: Context::default(),
+        };
+        let one_item = one_item.map(|a| Ok(a + 1)).unwrap();
+        assert_matches!(one_item.state.inspect(), 2);
+        assert_matches!(one_item.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn one_item_stack_can_consume_item() {
+        let one_item = OperationStack {
+            stack: SmallStack::<i32>::one_element(1),
+            state: OneItem(2),
+            context: Context::default(),
+        };
+        let mut consumed = None;
+        let no_items = one_item
+            .consume(|a| {
+                consumed = Some(a);
+                Ok(())
+            })
+            .unwrap();
+        assert_matches!(consumed, Some(2));
+        assert_matches!(no_items.stack.inspect(), (Some(1), None));
+    }
+
+    #[test]
+    fn one_item_stack_can_split_item() {
+        let one_item = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: OneItem(12),
+            context: Context::default(),
+        };
+        let two_items = one_item.split(|a| Ok((a / 10, a % 10))).unwrap();
+        assert_matches!(two_items.state.inspect(), (2, 1));
+        assert_matches!(two_items.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn one_item_stack_can_hold_other_types() {
+        let one_item = OperationStack {
+            stack: SmallStack::one_element(3),
+            state: OneItem(2),
+            context: Context::default(),
+        };
+        let two_items = one_item.map(|a| Ok((a, a))).unwrap().pop().unwrap();
+        let one_item = two_items.combine(|a, (b, c)| Ok(a * b + c)).unwrap();
+        assert_matches!(one_item.state.inspect(), 8);
+        assert_matches!(one_item.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn one_item_stack_can_push_all_items() {
+        let one_item = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: OneItem([1, 2]),
+            context: Context::default(),
+        };
+        let no_items = one_item.push_all().unwrap();
+        assert_matches!(no_items.stack.inspect(), (Some(1), Some(2)));
+    }
+
     #[test]
     fn two_item_stack_can_push_item() {
         let two_items = OperationStack {
-            stack: SmallStack::empty(),
+            stack: SmallStack::<i32>::empty(),
             state: TwoItems(2, 1),
+            context: Context::default(),
         };
         let one_item = two_items.push().unwrap();
         assert_matches!(one_item.state.inspect(), 2);
@@ -233,19 +733,127 @@ mod tests {
     #[test]
     fn two_item_stack_can_combine() {
         let two_items = OperationStack {
-            stack: SmallStack::empty(),
+            stack: SmallStack::<i32>::empty(),
             state: TwoItems(2, 1),
+            context: Context::default(),
         };
         let one_item = two_items.combine(|a, b| Ok(a + b)).unwrap();
         assert_matches!(one_item.state.inspect(), 3);
         assert_matches!(one_item.stack.inspect(), (None, None));
     }
 
+    #[test]
+    fn two_item_stack_can_pop_item() {
+        let two_items = OperationStack {
+            stack: SmallStack::one_element(1),
+            state: TwoItems(3, 2),
+            context: Context::default(),
+        };
+        let three_items = two_items.pop().unwrap();
+        assert_matches!(three_items.state.inspect(), (3, 2, 1));
+        assert_matches!(three_items.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn three_item_stack_can_push_items_in_order() {
+        let three_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: ThreeItems(3, 2, 1),
+            context: Context::default(),
+        };
+        let one_item = three_items.push().unwrap().push().unwrap();
+        assert_matches!(one_item.state.inspect(), 3);
+        assert_matches!(one_item.stack.inspect(), (Some(1), Some(2)));
+    }
+
+    #[test]
+    fn three_item_stack_can_combine3() {
+        let three_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: ThreeItems(3, 2, 1),
+            context: Context::default(),
+        };
+        let one_item = three_items
+            .combine3(|a, b, c| Ok(a * 100 + b * 10 + c))
+            .unwrap();
+        assert_matches!(one_item.state.inspect(), 123);
+        assert_matches!(one_item.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn three_item_stack_can_combine_first_items() {
+        let three_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: ThreeItems(3, 2, 1),
+            context: Context::default(),
+        };
+        let two_items = three_items.combine(|a, b| Ok(a * b)).unwrap();
+        assert_matches!(two_items.state.inspect(), (6, 1));
+    }
+
+    #[test]
+    fn three_item_stack_can_rotate_first_items() {
+        let three_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: ThreeItems(3, 2, 1),
+            context: Context::default(),
+        };
+        let three_items = three_items.rotate().unwrap();
+        assert_matches!(three_items.state.inspect(), (2, 3, 1));
+    }
+
+    #[test]
+    fn three_item_stack_can_rotate_last_item_to_top() {
+        let three_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: ThreeItems(3, 2, 1),
+            context: Context::default(),
+        };
+        let three_items = three_items.rotate3().unwrap();
+        assert_matches!(three_items.state.inspect(), (1, 3, 2));
+    }
+
+    #[test]
+    fn three_item_stack_can_pop_item() {
+        let three_items = OperationStack {
+            stack: SmallStack::one_element(1),
+            state: ThreeItems(4, 3, 2),
+            context: Context::default(),
+        };
+        let four_items = three_items.pop().unwrap();
+        assert_matches!(four_items.state.inspect(), (4, 3, 2, 1));
+        assert_matches!(four_items.stack.inspect(), (None, None));
+    }
+
+    #[test]
+    fn four_item_stack_can_combine4() {
+        let four_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: FourItems(4, 3, 2, 1),
+            context: Context::default(),
+        };
+        let one_item = four_items.combine4(|a, b, c, d| Ok(a * b + c * d)).unwrap();
+        assert_matches!(one_item.state.inspect(), 14);
+    }
+
+    #[test]
+    fn four_item_stack_can_push_item() {
+        let four_items = OperationStack {
+            stack: SmallStack::<i32>::empty(),
+            state: FourItems(4, 3, 2, 1),
+            context: Context::default(),
+        };
+        let three_items = four_items.rotate().unwrap().push().unwrap();
+        assert_matches!(three_items.state.inspect(), (3, 4, 2));
+        assert_matches!(three_items.stack.inspect(), (Some(1), None));
+    }
+
     #[test]
     fn pushing_on_full_stack_errors() {
         let one_item = OperationStack {
             stack: SmallStack::two_elements(1, 2),
             state: OneItem(3),
+            context: Context::default(),
         };
         let error = one_item.push();
         assert_matches!(
@@ -259,6 +867,7 @@ mod tests {
         let no_item = OperationStack {
             stack: SmallStack::<i32>::empty(),
             state: NoItems,
+            context: Context::default(),
         };
         let error = no_item.pop();
         assert_matches!(error, Err(OperationError::Stack(StackError::Empty)));
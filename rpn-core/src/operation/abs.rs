@@ -0,0 +1,44 @@
+use crate::number::Number;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the number on top of the stack by its absolute value
+pub fn abs<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack.pop()?.map(Number::abs)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn abs_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(abs);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn abs_errs_on_minimum_integer() {
+        let stack = SmallStack::one_element(i32::MIN);
+        let result = stack.evaluate(abs);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn abs_negates_negative_numbers() {
+        let stack = SmallStack::two_elements(-1, -2);
+        let result = stack.evaluate(abs);
+        assert_matches!(result.unwrap().inspect(), (Some(-1), Some(2)));
+    }
+}
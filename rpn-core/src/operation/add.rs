@@ -43,6 +43,22 @@ mod tests {
     fn add_errs_on_overflow() {
         let stack = SmallStack::<i8>::two_elements(i8::MAX, 2);
         let result = stack.evaluate(add);
-        assert_matches!(result, Err(OperationError::Number(NumberError::Unchecked)));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn add_error_shows_operands() {
+        extern crate alloc;
+        use alloc::string::ToString;
+        let stack = SmallStack::<i8>::two_elements(i8::MIN, -2);
+        let error = stack.evaluate(add).unwrap_err();
+        assert_matches!(error, OperationError::Number(NumberError::Underflow(_)));
+        assert_eq!(
+            error.to_string(),
+            "Number error: Underflow error in -128 + -2"
+        );
     }
 }
@@ -0,0 +1,37 @@
+use crate::number::{Complex, Float};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the complex number on top of the stack by its argument, in radians
+pub fn argument<S, F>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Complex<F>>,
+    F: Float,
+{
+    stack.pop()?.map(|z| Ok(z.argument().into()))?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn argument_errs_on_empty_stack() {
+        let stack = SmallStack::<Complex<f64>>::default();
+        let result = stack.evaluate(argument);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn argument_pushes_angle_from_real_axis() {
+        let stack = SmallStack::one_element(Complex::new(-1., 0.));
+        let result = stack.evaluate(argument);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Complex::new(PI, 0.)), None)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::number::Integer;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the integer on top of the stack by the number of its bits set to one
+pub fn count_ones<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.map(|a| Ok(Integer::count_ones(a)))?.push()
+}
+
+/// Replaces the integer on top of the stack by the number of zeros before its highest one bit
+pub fn leading_zeros<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.map(|a| Ok(Integer::leading_zeros(a)))?.push()
+}
+
+/// Replaces the integer on top of the stack by the number of zeros after its lowest one bit
+pub fn trailing_zeros<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.map(|a| Ok(Integer::trailing_zeros(a)))?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn count_ones_errs_on_empty_stack() {
+        let stack = SmallStack::<u8>::empty();
+        let result = stack.evaluate(count_ones);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn counts_bits() {
+        let stack = SmallStack::one_element(0b0010_1100u8);
+        assert_matches!(
+            stack.evaluate(count_ones).unwrap().inspect(),
+            (Some(3), None)
+        );
+        assert_matches!(
+            stack.evaluate(leading_zeros).unwrap().inspect(),
+            (Some(2), None)
+        );
+        assert_matches!(
+            stack.evaluate(trailing_zeros).unwrap().inspect(),
+            (Some(2), None)
+        );
+    }
+
+    #[test]
+    fn count_ones_of_negative_integer_counts_sign_bits() {
+        let stack = SmallStack::one_element(-1i16);
+        let result = stack.evaluate(count_ones);
+        assert_matches!(result.unwrap().inspect(), (Some(16), None));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::number::Integer;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Pushes the bitwise and of the first two integers on the stack
+pub fn and<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|a, b| Ok(Integer::and(a, b)))?
+        .push()
+}
+
+/// Pushes the bitwise or of the first two integers on the stack
+pub fn or<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|a, b| Ok(Integer::or(a, b)))?
+        .push()
+}
+
+/// Pushes the bitwise exclusive or of the first two integers on the stack
+pub fn xor<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|a, b| Ok(Integer::xor(a, b)))?
+        .push()
+}
+
+/// Flips every bit of the integer on top of the stack
+pub fn not<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.map(|a| Ok(Integer::not(a)))?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn and_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(1);
+        let result = stack.evaluate(and);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn and_or_xor_combine_bits() {
+        let stack = SmallStack::two_elements(0b1100, 0b1010);
+        assert_matches!(stack.evaluate(and).unwrap().inspect(), (Some(0b1000), None));
+        assert_matches!(stack.evaluate(or).unwrap().inspect(), (Some(0b1110), None));
+        assert_matches!(stack.evaluate(xor).unwrap().inspect(), (Some(0b0110), None));
+    }
+
+    #[test]
+    fn not_flips_bits() {
+        let stack = SmallStack::one_element(0b1010_0000u8);
+        let result = stack.evaluate(not);
+        assert_matches!(result.unwrap().inspect(), (Some(0b0101_1111), None));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::number::{Number, NumberError, Operands, ordering};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+use core::cmp::Ordering;
+
+/// Clamps the third number of the stack between the second one and the one on top,
+/// erring when the lower bound is above the upper one
+pub fn clamp<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack
+        .pop()?
+        .pop()?
+        .pop()?
+        .combine3(|value, low, high| {
+            let operation = format_args!("clamp({value:?}, {low:?}, {high:?})");
+            if ordering(&low, &high, operation)? == Ordering::Greater {
+                return Err(NumberError::DomainError(Operands::new(operation)).into());
+            }
+            if ordering(&value, &low, operation)? == Ordering::Less {
+                Ok(low)
+            } else if ordering(&value, &high, operation)? == Ordering::Greater {
+                Ok(high)
+            } else {
+                Ok(value)
+            }
+        })?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{LargeStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    fn clamped(items: &[f64]) -> Result<Option<f64>, OperationError> {
+        let mut stack = LargeStack::default();
+        for &item in items {
+            stack.push(item).unwrap();
+        }
+        let result = stack.evaluate(clamp)?;
+        assert_eq!(result.size(), items.len() - 2);
+        Ok(result.iter().last().copied())
+    }
+
+    #[test]
+    fn clamp_errs_on_2_element_stack() {
+        let result = clamped(&[0., 1.]);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn clamp_keeps_number_within_bounds() {
+        assert_matches!(clamped(&[5., -1., 0., 1.]), Ok(Some(0.)));
+        assert_matches!(clamped(&[2., 0., 1.]), Ok(Some(1.)));
+        assert_matches!(clamped(&[-2., 0., 1.]), Ok(Some(0.)));
+        assert_matches!(clamped(&[0.5, 0., 1.]), Ok(Some(0.5)));
+    }
+
+    #[test]
+    fn clamp_errs_on_inverted_bounds() {
+        let result = clamped(&[0.5, 1., 0.]);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn clamp_errs_on_not_a_number() {
+        let result = clamped(&[f64::NAN, 0., 1.]);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::number::{Number, ordering};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+use core::cmp::Ordering;
+
+/// Pushes back the smaller of the first two numbers on the stack
+pub fn min<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack.pop()?.pop()?.combine(smaller)?.push()
+}
+
+/// Pushes back the larger of the first two numbers on the stack
+pub fn max<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack.pop()?.pop()?.combine(larger)?.push()
+}
+
+/// Compares the second number of the stack to the one on top, pushing back -1 when it is
+/// smaller, 0 when they are equal and 1 when it is larger
+pub fn compare<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|a, b| {
+            let order = ordering(&a, &b, format_args!("compare({a:?}, {b:?})"))?;
+            signum(order)
+        })?
+        .push()
+}
+
+/// Smaller of two numbers, the first one when they are equal
+pub(crate) fn smaller<N: Number>(a: N, b: N) -> Result<N, OperationError> {
+    match ordering(&a, &b, format_args!("min({a:?}, {b:?})"))? {
+        Ordering::Greater => Ok(b),
+        _ => Ok(a),
+    }
+}
+
+/// Larger of two numbers, the first one when they are equal
+pub(crate) fn larger<N: Number>(a: N, b: N) -> Result<N, OperationError> {
+    match ordering(&a, &b, format_args!("max({a:?}, {b:?})"))? {
+        Ordering::Less => Ok(b),
+        _ => Ok(a),
+    }
+}
+
+/// Number standing for an ordering, as pushed by comparisons
+pub(crate) fn signum<N: Number>(order: Ordering) -> Result<N, OperationError> {
+    match order {
+        Ordering::Less => N::ONE.negate(),
+        Ordering::Equal => Ok(N::ZERO),
+        Ordering::Greater => Ok(N::ONE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Complex, NumberError};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn min_errs_on_1_element_stack() {
+        let stack = SmallStack::<i32>::one_element(1);
+        let result = stack.evaluate(min);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn min_and_max_push_back_one_number() {
+        let stack = SmallStack::<i32>::two_elements(3, -2);
+        let result = stack.clone().evaluate(min);
+        assert_matches!(result.unwrap().inspect(), (Some(-2), None));
+        let result = stack.evaluate(max);
+        assert_matches!(result.unwrap().inspect(), (Some(3), None));
+    }
+
+    #[test]
+    fn compare_pushes_sign_of_difference() {
+        let compared = |a, b| {
+            let stack = SmallStack::<f64>::two_elements(a, b);
+            stack.evaluate(compare).unwrap().inspect()
+        };
+        assert_matches!(compared(1., 2.), (Some(-1.), None));
+        assert_matches!(compared(2., 2.), (Some(0.), None));
+        assert_matches!(compared(3., 2.), (Some(1.), None));
+    }
+
+    #[test]
+    fn comparisons_err_on_not_a_number() {
+        let stack = SmallStack::two_elements(1., f64::NAN);
+        let result = stack.clone().evaluate(max);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+        let result = stack.evaluate(compare);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+    }
+
+    #[test]
+    fn comparisons_err_on_unordered_numbers() {
+        let i = "i".parse::<Complex<f64>>().unwrap();
+        let stack = SmallStack::two_elements(Complex::from(1.), i);
+        let result = stack.evaluate(min);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+}
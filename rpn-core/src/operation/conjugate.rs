@@ -0,0 +1,33 @@
+use crate::number::{Complex, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the complex number on top of the stack by its conjugate
+pub fn conjugate<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Complex<N>>,
+    N: Number,
+{
+    stack.pop()?.map(Complex::conjugate)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn conjugate_errs_on_empty_stack() {
+        let stack = SmallStack::<Complex<i32>>::default();
+        let result = stack.evaluate(conjugate);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn conjugate_negates_imaginary_part() {
+        let stack = SmallStack::one_element(Complex::new(3, 4));
+        let result = stack.evaluate(conjugate);
+        assert_eq!(result.unwrap().inspect(), (Some(Complex::new(3, -4)), None));
+    }
+}
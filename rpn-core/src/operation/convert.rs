@@ -0,0 +1,50 @@
+use crate::number::{Number, Quantity};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Converts the second quantity of the stack to the unit of the quantity on top
+pub fn convert<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Quantity<N>>,
+    N: Number,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|value, target| value.convert(target))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    fn quantity(s: &str) -> Quantity<f64> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn convert_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(quantity("1m"));
+        let result = stack.evaluate(convert);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn convert_errs_on_incompatible_dimensions() {
+        let stack = SmallStack::two_elements(quantity("1m"), quantity("s"));
+        let result = stack.evaluate(convert);
+        assert_matches!(result, Err(OperationError::IncompatibleDimensions));
+    }
+
+    #[test]
+    fn convert_keeps_value_in_new_unit() {
+        let stack = SmallStack::two_elements(quantity("1km"), quantity("m"));
+        let (top, rest) = stack.evaluate(convert).unwrap().inspect();
+        assert_eq!(top.unwrap().unit(), Some(&"m".parse().unwrap()));
+        assert_eq!(top, Some(quantity("1000m")));
+        assert_eq!(rest, None);
+    }
+}
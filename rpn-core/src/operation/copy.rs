@@ -5,7 +5,6 @@ use crate::stack::Stack;
 pub fn copy<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
 where
     S: Stack,
-    S::Item: Copy,
 {
     stack.pop()?.copy()?.push()?.push()
 }
@@ -0,0 +1,44 @@
+use crate::number::{Coordinate, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Computes the cross product of the first two 3D vectors on the stack and pushes back the result
+pub fn cross<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack.pop()?.pop()?.combine(Coordinate::cross)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Vec2, Vec3};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn cross_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Coordinate::from(Vec3::new(1, 2, 3)));
+        let result = stack.evaluate(cross);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn cross_errs_on_2d_vectors() {
+        let stack = SmallStack::two_elements(Vec2::new(1, 2).into(), Vec2::new(3, 4).into());
+        let result = stack.evaluate(cross);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn cross_pushes_cross_product() {
+        let stack = SmallStack::two_elements(Vec3::new(1, 0, 0).into(), Vec3::new(0, 1, 0).into());
+        let result = stack.evaluate(cross);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Vec3::new(0, 0, 1).into()), None)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::number::{Number, integer};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Pushes the number of items on the stack
+pub fn depth<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let depth = integer(stack.depth() as u128)?;
+    stack.hold(depth).push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::SmallStack;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn depth_pushes_0_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(depth);
+        assert_matches!(result.unwrap().inspect(), (Some(0), None));
+    }
+
+    #[test]
+    fn depth_pushes_number_of_items() {
+        let stack = SmallStack::<f64>::one_element(5.);
+        let result = stack.evaluate(depth);
+        assert_matches!(result.unwrap().inspect(), (Some(5.), Some(1.)));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::number::{Coordinate, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Computes the dot product of the first two vectors on the stack and pushes back the result
+pub fn dot<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack.pop()?.pop()?.combine(Coordinate::dot)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Vec2, Vec3};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn dot_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Coordinate::from(Vec2::new(1, 2)));
+        let result = stack.evaluate(dot);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn dot_errs_on_mismatched_dimensions() {
+        let stack = SmallStack::two_elements(Vec2::new(1, 2).into(), Vec3::new(1, 2, 3).into());
+        let result = stack.evaluate(dot);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn dot_pushes_dot_product() {
+        let stack = SmallStack::two_elements(Vec3::new(1, 2, 3).into(), Vec3::new(4, 5, 6).into());
+        let result = stack.evaluate(dot);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Coordinate::Scalar(32)), None)
+        );
+    }
+}
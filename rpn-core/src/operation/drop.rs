@@ -0,0 +1,112 @@
+use crate::number::Number;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Drops the top of the stack
+pub fn drop<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    stack.reorder(1, [])
+}
+
+/// Drops the second item of the stack, keeping the top
+pub fn nip<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    stack.reorder(2, [1])
+}
+
+/// Drops as many items as the number on top of the stack
+pub fn dropn<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, count) = stack.pop_count()?;
+    stack.reorder(count, [])
+}
+
+/// Drops every item of the stack
+pub fn clear<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    let depth = stack.depth();
+    stack.reorder(depth, [])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{LargeStack, SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn drop_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(drop);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn drop_removes_top() {
+        let stack = SmallStack::<i32>::two_elements(1, 2);
+        let result = stack.evaluate(drop);
+        assert_matches!(result.unwrap().inspect(), (Some(1), None));
+    }
+
+    #[test]
+    fn nip_errs_on_1_element_stack() {
+        let stack = SmallStack::<i32>::one_element(1);
+        let result = stack.evaluate(nip);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(1)))
+        );
+    }
+
+    #[test]
+    fn nip_removes_second_item() {
+        let stack = SmallStack::<i32>::two_elements(1, 2);
+        let result = stack.evaluate(nip);
+        assert_matches!(result.unwrap().inspect(), (Some(2), None));
+    }
+
+    #[test]
+    fn dropn_removes_counted_items() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 4, 2]);
+        let result = stack.evaluate(dropn).unwrap();
+        assert_eq!(result.items(), [1, 2]);
+    }
+
+    #[test]
+    fn dropn_errs_on_too_large_count() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3]);
+        let result = stack.evaluate(dropn);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(2)))
+        );
+    }
+
+    #[test]
+    fn dropn_errs_on_negative_or_fractional_count() {
+        let stack = LargeStack::<i32>::from_items(&[1, -1]);
+        let result = stack.evaluate(dropn);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+        let stack = LargeStack::<f64>::from_items(&[1., 0.5]);
+        let result = stack.evaluate(dropn);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn clear_removes_every_item() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3]);
+        let result = stack.evaluate(clear).unwrap();
+        assert_eq!(result.items(), []);
+        let result = result.evaluate(clear).unwrap();
+        assert_eq!(result.items(), []);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::number::Number;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Copies the second item of the stack onto the top
+pub fn over<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    stack.reorder(2, [0, 1, 0])
+}
+
+/// Copies the top of the stack below the second item
+pub fn tuck<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    stack.reorder(2, [1, 0, 1])
+}
+
+/// Copies the item at the level given by the number on top of the stack, 1 being the top,
+/// onto the top
+pub fn pick<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, level) = stack.pop_count()?;
+    if level == 0 {
+        return Err(OperationError::InvalidOperand);
+    }
+    stack.reorder(level, (0..level).chain([0]))
+}
+
+/// Copies as many items from the top of the stack as the number on top of it
+pub fn dupn<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, count) = stack.pop_count()?;
+    stack.reorder(count, (0..count).chain(0..count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{LargeStack, SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn over_errs_on_1_element_stack() {
+        let stack = SmallStack::<i32>::one_element(1);
+        let result = stack.evaluate(over);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(1)))
+        );
+    }
+
+    #[test]
+    fn over_copies_second_item() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2]);
+        let result = stack.evaluate(over).unwrap();
+        assert_eq!(result.items(), [1, 2, 1]);
+    }
+
+    #[test]
+    fn tuck_copies_top_below_second_item() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2]);
+        let result = stack.evaluate(tuck).unwrap();
+        assert_eq!(result.items(), [2, 1, 2]);
+    }
+
+    #[test]
+    fn pick_copies_item_at_level() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 3]);
+        let result = stack.evaluate(pick).unwrap();
+        assert_eq!(result.items(), [1, 2, 3, 1]);
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 1]);
+        let result = stack.evaluate(pick).unwrap();
+        assert_eq!(result.items(), [1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn pick_errs_on_invalid_level() {
+        let stack = LargeStack::<i32>::from_items(&[1, 0]);
+        let result = stack.evaluate(pick);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+        let stack = LargeStack::<i32>::from_items(&[1, 2]);
+        let result = stack.evaluate(pick);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(1)))
+        );
+    }
+
+    #[test]
+    fn dupn_copies_counted_items() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 2]);
+        let result = stack.evaluate(dupn).unwrap();
+        assert_eq!(result.items(), [1, 2, 3, 2, 3]);
+    }
+}
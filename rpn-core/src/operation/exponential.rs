@@ -0,0 +1,63 @@
+use crate::number::Float;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+float_operation!(
+    /// Raises e to the power of the number on top of the stack
+    exp,
+    /// Replaces the number on top of the stack by its natural logarithm
+    ln,
+    /// Replaces the number on top of the stack by its base 10 logarithm
+    log10,
+    /// Replaces the number on top of the stack by its base 2 logarithm
+    log2,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn exp_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(exp);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn exp_raises_e_to_power() {
+        let stack = SmallStack::one_element(0.);
+        let result = stack.evaluate(exp);
+        assert_eq!(result.unwrap().inspect(), (Some(1.), None));
+    }
+
+    #[test]
+    fn ln_errs_on_negative_number() {
+        let stack = SmallStack::one_element(-1.);
+        let result = stack.evaluate(ln);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn log10_errs_on_zero() {
+        let stack = SmallStack::one_element(0.);
+        let result = stack.evaluate(log10);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn log2_takes_base_2_logarithm() {
+        let stack = SmallStack::one_element(8f32);
+        let result = stack.evaluate(log2);
+        assert_eq!(result.unwrap().inspect(), (Some(3.), None));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::number::Float;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+float_operation!(
+    /// Replaces the number on top of the stack by its hyperbolic sine
+    sinh,
+    /// Replaces the number on top of the stack by its hyperbolic cosine
+    cosh,
+    /// Replaces the number on top of the stack by its hyperbolic tangent
+    tanh,
+    /// Replaces the number on top of the stack by its inverse hyperbolic sine
+    asinh,
+    /// Replaces the number on top of the stack by its inverse hyperbolic cosine
+    acosh,
+    /// Replaces the number on top of the stack by its inverse hyperbolic tangent
+    atanh,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn sinh_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(sinh);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn cosh_errs_on_overflow() {
+        let stack = SmallStack::one_element(1000.);
+        let result = stack.evaluate(cosh);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn acosh_errs_below_one() {
+        let stack = SmallStack::one_element(0.5);
+        let result = stack.evaluate(acosh);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn atanh_errs_on_pole() {
+        let stack = SmallStack::one_element(1.);
+        let result = stack.evaluate(atanh);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn tanh_takes_hyperbolic_tangent() {
+        let stack = SmallStack::one_element(0.);
+        let result = stack.evaluate(tanh);
+        assert_eq!(result.unwrap().inspect(), (Some(0.), None));
+    }
+}
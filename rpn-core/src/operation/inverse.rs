@@ -0,0 +1,43 @@
+use crate::number::Modular;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the modular number on top of the stack by its modular inverse
+pub fn inverse<S, const M: u64>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Modular<M>>,
+{
+    stack.pop()?.map(Modular::inverse)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn inverse_errs_on_empty_stack() {
+        let stack = SmallStack::<Modular<7>>::default();
+        let result = stack.evaluate(inverse);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn inverse_errs_on_non_invertible_number() {
+        let stack = SmallStack::one_element(Modular::<12>::new(4));
+        let result = stack.evaluate(inverse);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotInvertible(_)))
+        );
+    }
+
+    #[test]
+    fn inverse_pushes_modular_inverse() {
+        let stack = SmallStack::one_element(Modular::<7>::new(3));
+        let result = stack.evaluate(inverse);
+        assert_eq!(result.unwrap().inspect(), (Some(Modular::new(5)), None));
+    }
+}
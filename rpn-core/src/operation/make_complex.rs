@@ -0,0 +1,38 @@
+use crate::number::{Complex, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Builds a complex number from the second number on the stack as real part
+/// and the first number as imaginary part, and pushes back the result
+pub fn make_complex<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Complex<N>>,
+    N: Number,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|real, imaginary| real.add(imaginary.multiply(Complex::new(N::ZERO, N::ONE))?))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn make_complex_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Complex::new(1, 0));
+        let result = stack.evaluate(make_complex);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn make_complex_pushes_real_plus_imaginary_part() {
+        let stack = SmallStack::two_elements(Complex::new(3, 0), Complex::new(4, 0));
+        let result = stack.evaluate(make_complex);
+        assert_eq!(result.unwrap().inspect(), (Some(Complex::new(3, 4)), None));
+    }
+}
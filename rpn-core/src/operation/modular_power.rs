@@ -0,0 +1,45 @@
+use crate::number::Modular;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Operation raising the modular number on top of the stack to the power of `exponent`, which
+/// is a plain integer as one taken from the stack would already be reduced modulo `M`
+pub fn modular_power<S, const M: u64>(
+    exponent: u64,
+) -> impl FnOnce(OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Modular<M>>,
+{
+    move |stack| stack.pop()?.map(|base| Ok(base.power(exponent)))?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn modular_power_errs_on_empty_stack() {
+        let stack = SmallStack::<Modular<7>>::default();
+        let result = stack.evaluate(modular_power(3));
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn modular_power_raises_base_to_exponent() {
+        let stack = SmallStack::two_elements(Modular::<13>::new(1), Modular::new(2));
+        let result = stack.evaluate(modular_power(10));
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Modular::new(1)), Some(Modular::new(10)))
+        );
+    }
+
+    #[test]
+    fn modular_power_does_not_reduce_exponent() {
+        let stack = SmallStack::one_element(Modular::<7>::new(3));
+        let result = stack.evaluate(modular_power(10));
+        assert_eq!(result.unwrap().inspect(), (Some(Modular::new(4)), None));
+    }
+}
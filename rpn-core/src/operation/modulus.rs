@@ -0,0 +1,36 @@
+use crate::number::{Complex, Float};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the complex number on top of the stack by its modulus
+pub fn modulus<S, F>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Complex<F>>,
+    F: Float,
+{
+    stack.pop()?.map(|z| Ok(z.modulus().into()))?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn modulus_errs_on_empty_stack() {
+        let stack = SmallStack::<Complex<f64>>::default();
+        let result = stack.evaluate(modulus);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn modulus_pushes_distance_from_origin() {
+        let stack = SmallStack::one_element(Complex::new(3., -4.));
+        let result = stack.evaluate(modulus);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Complex::new(5., 0.)), None)
+        );
+    }
+}
@@ -43,6 +43,29 @@ mod tests {
     fn mul_errs_on_overflow() {
         let stack = SmallStack::<i8>::two_elements(42, 100);
         let result = stack.evaluate(multiply);
-        assert_matches!(result, Err(OperationError::Number(NumberError::Unchecked)));
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn mul_errs_on_not_a_number() {
+        let stack = SmallStack::two_elements(0., f64::INFINITY);
+        let result = stack.evaluate(multiply);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+    }
+
+    #[test]
+    fn mul_errs_on_float_underflow() {
+        let stack = SmallStack::two_elements(1e-200, 1e-200);
+        let result = stack.evaluate(multiply);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Underflow(_)))
+        );
     }
 }
@@ -0,0 +1,55 @@
+use crate::number::NumberConvert;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Checks that the number on top of the stack is a value of type `T`, such as a narrower
+/// integer type, erring rather than truncating it
+pub fn narrow<S, T>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: NumberConvert<T>,
+    T: NumberConvert<S::Item>,
+{
+    stack
+        .pop()?
+        .map(|n| NumberConvert::<T>::convert(n)?.convert())?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{NumberError, Value};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn narrow_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(narrow::<_, i8>);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn narrow_keeps_values_that_fit() {
+        let stack = SmallStack::<i32>::one_element(-128);
+        let result = stack.evaluate(narrow::<_, i8>);
+        assert_matches!(result.unwrap().inspect(), (Some(-128), None));
+    }
+
+    #[test]
+    fn narrow_errs_rather_than_truncating() {
+        let stack = SmallStack::<i32>::one_element(256);
+        let result = stack.evaluate(narrow::<_, u8>);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+        let stack = SmallStack::<Value>::one_element(Value::Float(1.5));
+        let result = stack.evaluate(narrow::<_, i32>);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::InexactResult(_)))
+        );
+    }
+}
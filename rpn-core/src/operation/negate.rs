@@ -0,0 +1,44 @@
+use crate::number::Number;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Negates the number on top of the stack
+pub fn negate<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack.pop()?.map(Number::negate)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn negate_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(negate);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn negate_errs_on_unsigned_number() {
+        let stack = SmallStack::<u32>::one_element(1);
+        let result = stack.evaluate(negate);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Underflow(_)))
+        );
+    }
+
+    #[test]
+    fn negate_negates_top_of_stack() {
+        let stack = SmallStack::two_elements(1, 2);
+        let result = stack.evaluate(negate);
+        assert_matches!(result.unwrap().inspect(), (Some(1), Some(-2)));
+    }
+}
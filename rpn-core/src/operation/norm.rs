@@ -0,0 +1,40 @@
+use crate::number::{Coordinate, Float};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the vector on top of the stack by its euclidean length
+pub fn norm<S, F>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<F>>,
+    F: Float,
+{
+    stack
+        .pop()?
+        .map(|v| Ok(Coordinate::Scalar(v.norm())))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Vec2;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn norm_errs_on_empty_stack() {
+        let stack = SmallStack::<Coordinate<f64>>::default();
+        let result = stack.evaluate(norm);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn norm_pushes_length_of_vector() {
+        let stack = SmallStack::one_element(Coordinate::from(Vec2::new(3., 4.)));
+        let result = stack.evaluate(norm);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Coordinate::Scalar(5.)), None)
+        );
+    }
+}
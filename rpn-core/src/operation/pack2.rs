@@ -0,0 +1,47 @@
+use crate::number::{Coordinate, Number, Vec2};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Packs the first two scalars on the stack into XY coordinates and pushes back the result
+pub fn pack2<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|x, y| Ok(Vec2::new(x.scalar()?, y.scalar()?).into()))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn pack2_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Coordinate::Scalar(1));
+        let result = stack.evaluate(pack2);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn pack2_errs_on_vectors() {
+        let stack = SmallStack::two_elements(Coordinate::Scalar(1), Vec2::new(2, 3).into());
+        let result = stack.evaluate(pack2);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn pack2_pushes_coordinates() {
+        let stack = SmallStack::two_elements(Coordinate::Scalar(1), Coordinate::Scalar(2));
+        let result = stack.evaluate(pack2);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Vec2::new(1, 2).into()), None)
+        );
+    }
+}
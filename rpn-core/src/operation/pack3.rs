@@ -0,0 +1,53 @@
+use crate::number::{Coordinate, Number, Vec3};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Packs the first three scalars on the stack into XYZ coordinates and pushes back the result
+pub fn pack3<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack
+        .pop()?
+        .map(Coordinate::scalar)?
+        .pop()?
+        .combine(|y, z| Ok((y.scalar()?, z)))?
+        .pop()?
+        .combine(|x, (y, z)| Ok(Vec3::new(x.scalar()?, y, z).into()))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Vec2;
+    use crate::stack::{LargeStack, SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn pack3_errs_on_2_element_stack() {
+        let stack = SmallStack::two_elements(Coordinate::Scalar(1), Coordinate::Scalar(2));
+        let result = stack.evaluate(pack3);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn pack3_errs_on_vectors() {
+        let stack = SmallStack::one_element(Coordinate::from(Vec2::new(1, 2)));
+        let result = stack.evaluate(pack3);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn pack3_pushes_coordinates() {
+        let mut stack = LargeStack::default();
+        for n in 1..=3 {
+            stack.push(Coordinate::Scalar(n)).unwrap();
+        }
+        let result = stack.evaluate(pack3).unwrap();
+        let mut items = result.iter();
+        assert_eq!(items.next(), Some(&Vec3::new(1, 2, 3).into()));
+        assert_eq!(items.next(), None);
+    }
+}
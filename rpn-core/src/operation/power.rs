@@ -0,0 +1,61 @@
+use crate::number::Power;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Raises the second number of the stack to the power of the number on top
+pub fn power<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Power,
+{
+    stack.pop()?.pop()?.combine(Power::power)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn power_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(2);
+        let result = stack.evaluate(power);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn power_errs_on_inexact_integer_result() {
+        let stack = SmallStack::two_elements(2, -1);
+        let result = stack.evaluate(power);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::InexactResult(_)))
+        );
+    }
+
+    #[test]
+    fn power_of_minus_one_takes_negative_exponent() {
+        let stack = SmallStack::two_elements(-1, -3);
+        let result = stack.evaluate(power);
+        assert_matches!(result.unwrap().inspect(), (Some(-1), None));
+    }
+
+    #[test]
+    fn power_errs_on_overflow() {
+        let stack = SmallStack::two_elements(2, 31);
+        let result = stack.evaluate(power);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+
+    #[test]
+    fn power_raises_second_to_top_of_stack() {
+        let stack = SmallStack::two_elements(2, 10);
+        let result = stack.evaluate(power);
+        assert_matches!(result.unwrap().inspect(), (Some(1024), None));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::number::{Number, integer};
+use crate::operation::comparison::{larger, smaller};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Operations reducing the whole stack, or as many items as the number on top of the stack,
+/// to a single number with a binary function
+macro_rules! reduce_operation {
+    ($(#[$doc:meta] $name:ident, $name_n:ident => $f:expr),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: Number,
+            {
+                let count = stack.depth();
+                stack.reduce(count, $f)?.push()
+            }
+
+            #[$doc]
+            pub fn $name_n<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: Number,
+            {
+                let (stack, count) = stack.pop_count()?;
+                stack.reduce(count, $f)?.push()
+            }
+        )*
+    };
+}
+
+reduce_operation!(
+    /// Adds the numbers on the stack
+    sum, sum_n => Number::add,
+    /// Multiplies the numbers on the stack
+    product, product_n => Number::multiply,
+    /// Keeps the smallest of the numbers on the stack
+    minimum, minimum_n => smaller,
+    /// Keeps the largest of the numbers on the stack
+    maximum, maximum_n => larger,
+);
+
+/// Averages the numbers on the stack
+pub fn mean<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let count = stack.depth();
+    average(stack, count)
+}
+
+/// Averages as many numbers as the number on top of the stack
+pub fn mean_n<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, count) = stack.pop_count()?;
+    average(stack, count)
+}
+
+fn average<S>(stack: OpStack<S>, count: usize) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack
+        .reduce(count, Number::add)?
+        .map(|sum| sum.divide(integer(count as u128)?))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{LargeStack, SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[test]
+    fn sum_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(sum);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn sum_keeps_single_number() {
+        let stack = SmallStack::<i32>::one_element(3);
+        let result = stack.evaluate(sum);
+        assert_matches!(result.unwrap().inspect(), (Some(3), None));
+    }
+
+    #[test]
+    fn reductions_reduce_whole_stack() {
+        let reduced = |operation: fn(OpStack<LargeStack<i32>>) -> _| {
+            let stack = LargeStack::<i32>::from_items(&[3, 1, 4, 2]);
+            let result: LargeStack<i32> = stack.evaluate(operation).unwrap();
+            assert_eq!(result.size(), 1);
+            result.items()[0]
+        };
+        assert_eq!(reduced(sum), 10);
+        assert_eq!(reduced(product), 24);
+        assert_eq!(reduced(minimum), 1);
+        assert_eq!(reduced(maximum), 4);
+        assert_eq!(reduced(mean), 2);
+    }
+
+    #[test]
+    fn reductions_reduce_counted_items() {
+        let stack = LargeStack::<f64>::from_items(&[9., 1., 2., 2.]);
+        let result = stack.evaluate(mean_n).unwrap();
+        assert_eq!(result.items(), [9., 1.5]);
+        let stack = LargeStack::<f64>::from_items(&[9., 1., 2., 3.]);
+        let result = stack.evaluate(maximum_n).unwrap();
+        assert_eq!(result.items(), [9.]);
+    }
+
+    #[test]
+    fn reductions_err_on_too_large_count() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3]);
+        let result = stack.evaluate(sum_n);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(2)))
+        );
+    }
+
+    #[test]
+    fn sum_errs_as_repeated_additions() {
+        let stack = LargeStack::<i8>::from_items(&[1, 100, 27, 1]);
+        let error = stack.evaluate(sum).unwrap_err();
+        assert_matches!(error, OperationError::Number(NumberError::Overflow(_)));
+        assert_eq!(
+            error.to_string(),
+            "Number error: Overflow error in 100 + 28"
+        );
+    }
+
+    #[test]
+    fn minimum_errs_on_not_a_number() {
+        let stack = LargeStack::<f64>::from_items(&[1., f64::NAN, 2.]);
+        let result = stack.evaluate(minimum);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+    }
+}
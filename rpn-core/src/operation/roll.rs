@@ -0,0 +1,96 @@
+use crate::number::Number;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Moves the third item of the stack onto the top, as HP calculators' `ROT`
+pub fn rot3<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+{
+    stack.reorder(3, [1, 2, 0])
+}
+
+/// Moves the item at the level given by the number on top of the stack, 1 being the top,
+/// onto the top, rolling the items above it down a level, as HP calculators' `ROLL`
+pub fn roll<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, level) = stack.pop_count()?;
+    if level == 0 {
+        return Err(OperationError::InvalidOperand);
+    }
+    stack.reorder(level, (1..level).chain([0]))
+}
+
+/// Moves the top of the stack down to the level given by the number on top of it, rolling the
+/// items above that level up, as HP calculators' `ROLLD`
+pub fn roll_down<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    let (stack, level) = stack.pop_count()?;
+    if level == 0 {
+        return Err(OperationError::InvalidOperand);
+    }
+    stack.reorder(level, [level - 1].into_iter().chain(0..level - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{LargeStack, SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn rot3_errs_on_2_element_stack() {
+        let stack = SmallStack::<i32>::two_elements(1, 2);
+        let result = stack.evaluate(rot3);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(2)))
+        );
+    }
+
+    #[test]
+    fn rot3_moves_third_item_to_top() {
+        let stack = LargeStack::<i32>::from_items(&[0, 1, 2, 3]);
+        let result = stack.evaluate(rot3).unwrap();
+        assert_eq!(result.items(), [0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn roll_moves_item_at_level_to_top() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 4, 3]);
+        let result = stack.evaluate(roll).unwrap();
+        assert_eq!(result.items(), [1, 3, 4, 2]);
+    }
+
+    #[test]
+    fn roll_down_moves_top_to_level() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3, 4, 3]);
+        let result = stack.evaluate(roll_down).unwrap();
+        assert_eq!(result.items(), [1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn roll_moves_items_deeper_than_reorder_buffer() {
+        let mut items: [i32; 21] = core::array::from_fn(|i| i as i32);
+        let stack = LargeStack::<i32>::from_items(&items);
+        let result = stack.evaluate(roll).unwrap();
+        items[..20].rotate_left(1);
+        assert_eq!(result.items(), &items[..20]);
+    }
+
+    #[test]
+    fn roll_errs_on_too_deep_level() {
+        let stack = LargeStack::<i32>::from_items(&[1, 2, 3]);
+        let result = stack.evaluate(roll);
+        assert_matches!(
+            result,
+            Err(OperationError::Stack(StackError::TooShallow(2)))
+        );
+    }
+}
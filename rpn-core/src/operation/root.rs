@@ -0,0 +1,51 @@
+use crate::number::Power;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Takes the root of the second number of the stack, of the degree on top
+pub fn root<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Power,
+{
+    stack.pop()?.pop()?.combine(Power::nth_root)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn root_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(8);
+        let result = stack.evaluate(root);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn root_errs_on_even_root_of_negative_number() {
+        let stack = SmallStack::two_elements(-16, 4);
+        let result = stack.evaluate(root);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn root_rounds_toward_zero() {
+        let stack = SmallStack::two_elements(-30, 3);
+        let result = stack.evaluate(root);
+        assert_matches!(result.unwrap().inspect(), (Some(-3), None));
+    }
+
+    #[test]
+    fn root_takes_float_roots() {
+        let stack = SmallStack::two_elements(-27., 3.);
+        let result = stack.evaluate(root);
+        assert_eq!(result.unwrap().inspect(), (Some(-3.), None));
+    }
+}
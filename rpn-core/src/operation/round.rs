@@ -0,0 +1,78 @@
+use crate::number::{NumberConvert, RoundingConvert, RoundingMode};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Operation rounding the number on top of the stack to an integer in one of the rounding modes
+macro_rules! rounding_operation {
+    ($(#[$doc:meta] $name:ident => $rounding:ident),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: RoundingConvert<i64>,
+                i64: NumberConvert<S::Item>,
+            {
+                stack
+                    .pop()?
+                    .map(|n| n.convert_rounding(RoundingMode::$rounding)?.convert())?
+                    .push()
+            }
+        )*
+    };
+}
+
+rounding_operation!(
+    /// Rounds the number on top of the stack down to an integer
+    floor => Floor,
+    /// Rounds the number on top of the stack up to an integer
+    ceil => Ceil,
+    /// Rounds the number on top of the stack to the nearest integer, halfway cases away from zero
+    round => Round,
+    /// Rounds the number on top of the stack toward zero to an integer
+    truncate => Trunc,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{NumberError, Value};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn round_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(round);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn rounding_operations_round_floats_to_integers() {
+        let rounded = |operation: fn(OpStack<SmallStack<Value>>) -> _| {
+            let stack = SmallStack::one_element(Value::Float(-2.5));
+            stack.evaluate(operation).unwrap().inspect()
+        };
+        assert_matches!(rounded(floor), (Some(Value::Integer(-3)), None));
+        assert_matches!(rounded(ceil), (Some(Value::Integer(-2)), None));
+        assert_matches!(rounded(round), (Some(Value::Integer(-3)), None));
+        assert_matches!(rounded(truncate), (Some(Value::Integer(-2)), None));
+    }
+
+    #[test]
+    fn round_keeps_floats_of_float_stacks() {
+        let stack = SmallStack::one_element(2.5);
+        let result = stack.evaluate(round);
+        assert_matches!(result.unwrap().inspect(), (Some(3.), None));
+    }
+
+    #[test]
+    fn round_errs_outside_of_integer_range() {
+        let stack = SmallStack::one_element(1e19);
+        let result = stack.evaluate(floor);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+    }
+}
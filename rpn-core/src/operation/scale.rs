@@ -0,0 +1,44 @@
+use crate::number::{Coordinate, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Multiplies the vector by the scalar among the first two items on the stack and pushes back the result
+pub fn scale<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack.pop()?.pop()?.combine(Coordinate::scale)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Vec2;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn scale_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Coordinate::Scalar(1));
+        let result = stack.evaluate(scale);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn scale_errs_on_two_scalars() {
+        let stack = SmallStack::two_elements(Coordinate::Scalar(1), Coordinate::Scalar(2));
+        let result = stack.evaluate(scale);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn scale_pushes_scaled_vector() {
+        let stack = SmallStack::two_elements(Vec2::new(1, 2).into(), Coordinate::Scalar(3));
+        let result = stack.evaluate(scale);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Vec2::new(3, 6).into()), None)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::number::Integer;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Shifts the second integer on the stack left by the number of bits on top
+pub fn shift_left<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.pop()?.combine(Integer::shift_left)?.push()
+}
+
+/// Shifts the second integer on the stack right by the number of bits on top, filling with zeros
+pub fn shift_right<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack.pop()?.pop()?.combine(Integer::shift_right)?.push()
+}
+
+/// Shifts the second integer on the stack right by the number of bits on top,
+/// keeping its sign bit
+pub fn arithmetic_shift_right<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(Integer::arithmetic_shift_right)?
+        .push()
+}
+
+/// Rotates the bits of the second integer on the stack left by the number on top
+pub fn rotate_bits_left<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(Integer::rotate_bits_left)?
+        .push()
+}
+
+/// Rotates the bits of the second integer on the stack right by the number on top
+pub fn rotate_bits_right<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Integer,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(Integer::rotate_bits_right)?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn shift_left_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(1);
+        let result = stack.evaluate(shift_left);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn shift_left_shifts_second_by_top() {
+        let stack = SmallStack::two_elements(1, 4);
+        let result = stack.evaluate(shift_left);
+        assert_matches!(result.unwrap().inspect(), (Some(16), None));
+    }
+
+    #[test]
+    fn shift_errs_on_amount_of_bit_width() {
+        let stack = SmallStack::<u8>::two_elements(1, 8);
+        let result = stack.evaluate(shift_left);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+        let stack = SmallStack::two_elements(1, -1);
+        let result = stack.evaluate(shift_right);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn shift_right_is_logical_or_arithmetic() {
+        let stack = SmallStack::two_elements(-16i8, 2);
+        let result = stack.evaluate(shift_right);
+        assert_matches!(result.unwrap().inspect(), (Some(0b0011_1100), None));
+        let result = stack.evaluate(arithmetic_shift_right);
+        assert_matches!(result.unwrap().inspect(), (Some(-4), None));
+        let stack = SmallStack::two_elements(0b1000_0000u8, 1);
+        let result = stack.evaluate(arithmetic_shift_right);
+        assert_matches!(result.unwrap().inspect(), (Some(0b1100_0000), None));
+    }
+
+    #[test]
+    fn rotate_bits_wraps_bits_around() {
+        let stack = SmallStack::two_elements(0b1000_0001u8, 1);
+        let result = stack.evaluate(rotate_bits_left);
+        assert_matches!(result.unwrap().inspect(), (Some(0b0000_0011), None));
+        let result = stack.evaluate(rotate_bits_right);
+        assert_matches!(result.unwrap().inspect(), (Some(0b1100_0000), None));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::number::{Number, ordering};
+use crate::operation::comparison::signum;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the number on top of the stack by -1, 0 or 1, depending on its sign
+pub fn sign<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Number,
+{
+    stack
+        .pop()?
+        .map(|n| signum(ordering(&n, &S::Item::ZERO, format_args!("sign({n:?})"))?))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn sign_errs_on_empty_stack() {
+        let stack = SmallStack::<i32>::empty();
+        let result = stack.evaluate(sign);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn sign_pushes_sign_of_number() {
+        let signed = |n| {
+            let stack = SmallStack::<i32>::one_element(n);
+            stack.evaluate(sign).unwrap().inspect()
+        };
+        assert_matches!(signed(-42), (Some(-1), None));
+        assert_matches!(signed(0), (Some(0), None));
+        assert_matches!(signed(7), (Some(1), None));
+    }
+
+    #[test]
+    fn sign_errs_on_not_a_number() {
+        let stack = SmallStack::one_element(f64::NAN);
+        let result = stack.evaluate(sign);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::NotANumber(_)))
+        );
+    }
+}
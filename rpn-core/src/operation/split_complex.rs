@@ -0,0 +1,40 @@
+use crate::number::{Complex, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Splits the complex number on top of the stack into its real part followed by its imaginary part
+pub fn split_complex<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Complex<N>>,
+    N: Number,
+{
+    stack
+        .pop()?
+        .split(|z| Ok((z.real().clone().into(), z.imaginary().clone().into())))?
+        .push()?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn split_complex_errs_on_empty_stack() {
+        let stack = SmallStack::<Complex<i32>>::default();
+        let result = stack.evaluate(split_complex);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn split_complex_pushes_real_and_imaginary_parts() {
+        let stack = SmallStack::one_element(Complex::new(3, 4));
+        let result = stack.evaluate(split_complex);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Complex::new(3, 0)), Some(Complex::new(4, 0)))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::number::Power;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the number on top of the stack by its square root
+pub fn sqrt<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Power,
+{
+    stack.pop()?.map(Power::sqrt)?.push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::NumberError;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn sqrt_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(sqrt);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn sqrt_errs_on_negative_number() {
+        let stack = SmallStack::one_element(-1);
+        let result = stack.evaluate(sqrt);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn sqrt_rounds_integers_down() {
+        let stack = SmallStack::one_element(17);
+        let result = stack.evaluate(sqrt);
+        assert_matches!(result.unwrap().inspect(), (Some(4), None));
+    }
+
+    #[test]
+    fn sqrt_takes_float_square_root() {
+        let stack = SmallStack::one_element(2.25);
+        let result = stack.evaluate(sqrt);
+        assert_eq!(result.unwrap().inspect(), (Some(1.5), None));
+    }
+}
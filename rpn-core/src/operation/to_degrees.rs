@@ -0,0 +1,37 @@
+use crate::number::{AngleMode, Float};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Converts the angle in radians on top of the stack to degrees
+pub fn to_degrees<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Float,
+{
+    stack
+        .pop()?
+        .map(|angle| AngleMode::Degrees.from_radians(angle))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn to_degrees_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(to_degrees);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn to_degrees_converts_radians() {
+        let stack = SmallStack::one_element(PI);
+        let result = stack.evaluate(to_degrees);
+        assert_eq!(result.unwrap().inspect(), (Some(180.), None));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::number::NumberConvert;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Converts the number on top of the stack to a float
+pub fn to_float<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: NumberConvert<f64>,
+    f64: NumberConvert<S::Item>,
+{
+    stack
+        .pop()?
+        .map(|n| NumberConvert::<f64>::convert(n)?.convert())?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Rational, Value};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn to_float_errs_on_empty_stack() {
+        let stack = SmallStack::<Value>::empty();
+        let result = stack.evaluate(to_float);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn to_float_converts_integers_and_rationals() {
+        let stack = SmallStack::<Value>::one_element(Value::Integer(3));
+        let result = stack.evaluate(to_float);
+        assert_matches!(result.unwrap().inspect(), (Some(Value::Float(3.)), None));
+        let stack = SmallStack::<Value>::one_element(Rational::new(1, 4).unwrap().into());
+        let result = stack.evaluate(to_float);
+        assert_matches!(result.unwrap().inspect(), (Some(Value::Float(0.25)), None));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::number::{AngleMode, Float};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Converts the angle in degrees on top of the stack to radians
+pub fn to_radians<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: Float,
+{
+    stack
+        .pop()?
+        .map(|angle| AngleMode::Degrees.to_radians(angle))?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn to_radians_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(to_radians);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn to_radians_converts_degrees() {
+        let stack = SmallStack::one_element(180.);
+        let result = stack.evaluate(to_radians);
+        assert_eq!(result.unwrap().inspect(), (Some(PI), None));
+    }
+}
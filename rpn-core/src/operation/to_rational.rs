@@ -0,0 +1,55 @@
+use crate::number::{ApproximateConvert, NumberConvert, Rational};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Approximates the second number of the stack by a fraction whose denominator is at most the
+/// integer on top of the stack
+pub fn to_rational<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack,
+    S::Item: NumberConvert<i64> + ApproximateConvert<i64>,
+    Rational<i64>: NumberConvert<S::Item>,
+{
+    stack
+        .pop()?
+        .pop()?
+        .combine(|value, max_denominator| {
+            value
+                .convert_approximate(max_denominator.convert()?)?
+                .convert()
+        })?
+        .push()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{NumberError, Value};
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn to_rational_errs_on_1_element_stack() {
+        let stack = SmallStack::one_element(Value::<true>::Float(0.5));
+        let result = stack.evaluate(to_rational);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn to_rational_approximates_floats() {
+        let stack = SmallStack::<Value>::two_elements(Value::Float(0.333), Value::Integer(10));
+        let (top, rest) = stack.evaluate(to_rational).unwrap().inspect();
+        assert_eq!(top, Some(Rational::new(1, 3).unwrap().into()));
+        assert_eq!(rest, None);
+    }
+
+    #[test]
+    fn to_rational_errs_on_fractional_max_denominator() {
+        let stack = SmallStack::<Value>::two_elements(Value::Float(0.5), Value::Float(2.5));
+        let result = stack.evaluate(to_rational);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::InexactResult(_)))
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::number::Float;
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Operation taking the sine, cosine or tangent of an angle in the current angle mode
+macro_rules! angle_operation {
+    ($(#[$doc:meta] $name:ident),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: Float,
+            {
+                let mode = stack.context().angle_mode;
+                stack
+                    .pop()?
+                    .map(|angle| mode.to_radians(angle)?.$name())?
+                    .push()
+            }
+        )*
+    };
+}
+
+/// Operation taking an inverse trigonometric function, giving an angle in the current angle mode
+macro_rules! inverse_angle_operation {
+    ($(#[$doc:meta] $name:ident),* $(,)?) => {
+        $(
+            #[$doc]
+            pub fn $name<S>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+            where
+                S: Stack,
+                S::Item: Float,
+            {
+                let mode = stack.context().angle_mode;
+                stack
+                    .pop()?
+                    .map(|n| mode.from_radians(n.$name()?))?
+                    .push()
+            }
+        )*
+    };
+}
+
+angle_operation!(
+    /// Replaces the angle on top of the stack by its sine
+    sin,
+    /// Replaces the angle on top of the stack by its cosine
+    cos,
+    /// Replaces the angle on top of the stack by its tangent
+    tan,
+);
+
+inverse_angle_operation!(
+    /// Replaces the number on top of the stack by its arcsine
+    asin,
+    /// Replaces the number on top of the stack by its arccosine
+    acos,
+    /// Replaces the number on top of the stack by its arctangent
+    atan,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{AngleMode, NumberError};
+    use crate::operation::Context;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+    use core::f64::consts::FRAC_PI_2;
+
+    const DEGREES: Context = Context {
+        angle_mode: AngleMode::Degrees,
+    };
+
+    #[test]
+    fn sin_errs_on_empty_stack() {
+        let stack = SmallStack::<f64>::empty();
+        let result = stack.evaluate(sin);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn sin_takes_sine_of_radians() {
+        let stack = SmallStack::one_element(FRAC_PI_2);
+        let result = stack.evaluate(sin);
+        assert_eq!(result.unwrap().inspect(), (Some(1.), None));
+    }
+
+    #[test]
+    fn asin_errs_outside_of_domain() {
+        let stack = SmallStack::one_element(2.);
+        let result = stack.evaluate(asin);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DomainError(_)))
+        );
+    }
+
+    #[test]
+    fn acos_returns_radians() {
+        let stack = SmallStack::one_element(0.);
+        let result = stack.evaluate(acos);
+        assert_eq!(result.unwrap().inspect(), (Some(FRAC_PI_2), None));
+    }
+
+    #[test]
+    fn sin_takes_sine_of_degrees() {
+        let stack = SmallStack::one_element(90.);
+        let result = stack.evaluate_in(DEGREES, sin);
+        assert_eq!(result.unwrap().inspect(), (Some(1.), None));
+    }
+
+    #[test]
+    fn asin_returns_degrees() {
+        let stack = SmallStack::one_element(1.);
+        let result = stack.evaluate_in(DEGREES, asin);
+        assert_eq!(result.unwrap().inspect(), (Some(90.), None));
+    }
+}
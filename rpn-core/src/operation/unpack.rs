@@ -0,0 +1,44 @@
+use crate::number::{Coordinate, Number};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Replaces the vector on top of the stack by its scalar components, the last one on top
+pub fn unpack<S, N>(stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+where
+    S: Stack<Item = Coordinate<N>>,
+    N: Number,
+{
+    stack.pop()?.map(Coordinate::components)?.push_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Vec2;
+    use crate::stack::{SmallStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn unpack_errs_on_empty_stack() {
+        let stack = SmallStack::<Coordinate<i32>>::default();
+        let result = stack.evaluate(unpack);
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+    }
+
+    #[test]
+    fn unpack_errs_on_scalar() {
+        let stack = SmallStack::one_element(Coordinate::Scalar(1));
+        let result = stack.evaluate(unpack);
+        assert_matches!(result, Err(OperationError::InvalidOperand));
+    }
+
+    #[test]
+    fn unpack_pushes_components() {
+        let stack = SmallStack::one_element(Coordinate::from(Vec2::new(1, 2)));
+        let result = stack.evaluate(unpack);
+        assert_eq!(
+            result.unwrap().inspect(),
+            (Some(Coordinate::Scalar(1)), Some(Coordinate::Scalar(2)))
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::number::{Integer, Number, integer};
+use crate::operation::OperationError;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// Digits of every radix up to 36, in order of value
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Most digits an integer can be displayed with, that of a 128 bit integer in binary
+const MAX_DIGITS: usize = 128;
+
+/// Radix integers are displayed in, along with the word size of the two's complement view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RadixFormat {
+    radix: u32,
+    word_size: Option<u32>,
+}
+
+impl RadixFormat {
+    pub const DECIMAL: Self = Self::of(10);
+    pub const HEXADECIMAL: Self = Self::of(16);
+    pub const OCTAL: Self = Self::of(8);
+    pub const BINARY: Self = Self::of(2);
+
+    const fn of(radix: u32) -> Self {
+        Self {
+            radix,
+            word_size: None,
+        }
+    }
+
+    /// Format of the given radix, erring when it is not within `2..=36`
+    pub fn new(radix: u32) -> Result<Self, RadixError> {
+        match radix {
+            2..=36 => Ok(Self::of(radix)),
+            _ => Err(RadixError::Radix),
+        }
+    }
+
+    /// Shows integers as the two's complement of their lowest `word_size` bits, which only
+    /// radices that are powers of two can show digit by digit
+    pub fn with_word_size(self, word_size: u32) -> Result<Self, RadixError> {
+        if !self.radix.is_power_of_two() {
+            return Err(RadixError::Radix);
+        }
+        if !(1..=MAX_DIGITS as u32).contains(&word_size) {
+            return Err(RadixError::WordSize);
+        }
+        Ok(Self {
+            word_size: Some(word_size),
+            ..self
+        })
+    }
+
+    /// Shows negative integers with a minus sign
+    pub fn without_word_size(self) -> Self {
+        Self::of(self.radix)
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    pub fn word_size(&self) -> Option<u32> {
+        self.word_size
+    }
+
+    pub fn display<N>(self, n: &N) -> RadixDisplay<'_, N> {
+        RadixDisplay { n, format: self }
+    }
+
+    fn prefix(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.radix {
+            16 => f.write_str("0x"),
+            8 => f.write_str("0o"),
+            2 => f.write_str("0b"),
+            10 => Ok(()),
+            radix => f.write_fmt(format_args!("{radix}#")),
+        }
+    }
+}
+
+impl Default for RadixFormat {
+    fn default() -> Self {
+        Self::DECIMAL
+    }
+}
+
+impl Display for RadixFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.radix {
+            16 => f.write_str("HEX")?,
+            10 => f.write_str("DEC")?,
+            8 => f.write_str("OCT")?,
+            2 => f.write_str("BIN")?,
+            radix => f.write_fmt(format_args!("BASE {radix}"))?,
+        }
+        match self.word_size {
+            Some(word_size) => f.write_fmt(format_args!(" {word_size}-BIT")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Integer displayed in a [`RadixFormat`]
+pub struct RadixDisplay<'a, N> {
+    n: &'a N,
+    format: RadixFormat,
+}
+
+impl<N: Integer> RadixDisplay<'_, N> {
+    /// Digits from the lowest, of the absolute value with whether it is negative
+    fn signed_digits(
+        &self,
+        digits: &mut [u8; MAX_DIGITS],
+    ) -> Result<(usize, bool), OperationError> {
+        let radix: N = integer(u128::from(self.format.radix))?;
+        let negative = *self.n < N::ZERO;
+        let (mut n, mut len) = (self.n.clone(), 0);
+        loop {
+            let remainder = n.clone().remainder(radix.clone())?;
+            let digit = if negative {
+                remainder.negate()?
+            } else {
+                remainder
+            };
+            digits[len] = DIGITS[digit_value(digit)?];
+            (n, len) = (n.divide(radix.clone())?, len + 1);
+            if n == N::ZERO {
+                return Ok((len, negative));
+            }
+        }
+    }
+
+    /// Digits from the lowest of the two's complement of the lowest `word_size` bits, negative
+    /// numbers narrower than the word extended with their sign
+    fn word_digits(
+        &self,
+        word_size: u32,
+        digits: &mut [u8; MAX_DIGITS],
+    ) -> Result<usize, OperationError> {
+        let bits = self.format.radix.trailing_zeros();
+        let negative = *self.n < N::ZERO;
+        let mut len = 0;
+        for shift in (0..word_size).step_by(bits as usize) {
+            let amount = integer(u128::from(shift))?;
+            let shifted = match negative {
+                true => self.n.clone().arithmetic_shift_right(amount),
+                false => self.n.clone().shift_right(amount),
+            };
+            // Shifting by the bit width of the type errs, past which only the sign is left
+            let shifted = shifted.unwrap_or(match negative {
+                true => N::ZERO.not(),
+                false => N::ZERO,
+            });
+            let width = bits.min(word_size - shift);
+            let digit = shifted.and(integer((1 << width) - 1)?);
+            digits[len] = DIGITS[digit_value(digit)?];
+            len += 1;
+        }
+        while len > 1 && digits[len - 1] == b'0' {
+            len -= 1;
+        }
+        Ok(len)
+    }
+}
+
+impl<N: Integer + Display> Display for RadixDisplay<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.format == RadixFormat::DECIMAL {
+            return Display::fmt(self.n, f);
+        }
+        let mut digits = [0; MAX_DIGITS];
+        let digits_and_sign = match self.format.word_size {
+            Some(word_size) => self
+                .word_digits(word_size, &mut digits)
+                .map(|len| (len, false)),
+            None => self.signed_digits(&mut digits),
+        };
+        let (len, negative) = digits_and_sign.map_err(|_| core::fmt::Error)?;
+        if negative {
+            f.write_str("-")?;
+        }
+        self.format.prefix(f)?;
+        digits[..len]
+            .iter()
+            .rev()
+            .try_for_each(|&digit| f.write_fmt(format_args!("{}", char::from(digit))))
+    }
+}
+
+/// Value of a digit, found by counting up to it as numbers only provide arithmetic
+fn digit_value<N: Number>(digit: N) -> Result<usize, OperationError> {
+    let (mut value, mut count) = (N::ZERO, 0);
+    while value < digit && count < DIGITS.len() {
+        (value, count) = (value.add(N::ONE)?, count + 1);
+    }
+    Ok(count)
+}
+
+/// Parses `0x`, `0o` and `0b` literals and `radix#digits` ones such as `3#1201`, in any case,
+/// with an optional minus sign and `_` separators between digits
+pub fn parse<N: Number>(s: &str) -> Result<N, RadixError> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, s),
+    };
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x" | "0X") => (16, &unsigned[2..]),
+        Some("0o" | "0O") => (8, &unsigned[2..]),
+        Some("0b" | "0B") => (2, &unsigned[2..]),
+        _ => {
+            let (radix, digits) = unsigned.split_once('#').ok_or(RadixError::Prefix)?;
+            let radix = radix.parse().map_err(|_| RadixError::Radix)?;
+            (RadixFormat::new(radix)?.radix, digits)
+        }
+    };
+    if digits.is_empty() {
+        return Err(RadixError::Digit);
+    }
+    let overflow = |_| RadixError::Overflow;
+    let base: N = integer(u128::from(radix)).map_err(overflow)?;
+    digits
+        .chars()
+        .filter(|&c| c != '_')
+        .try_fold(N::ZERO, |value, c| {
+            let digit = c.to_digit(radix).ok_or(RadixError::Digit)?;
+            let digit = integer(u128::from(digit)).map_err(overflow)?;
+            let value = value.multiply(base.clone()).map_err(overflow)?;
+            // Subtracting the digits of negative numbers lets the most negative integer through
+            match negative {
+                false => value.add(digit),
+                true => value.subtract(digit),
+            }
+            .map_err(overflow)
+        })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RadixError {
+    /// The literal has no radix prefix
+    Prefix,
+    Radix,
+    WordSize,
+    Digit,
+    Overflow,
+}
+
+impl Display for RadixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            RadixError::Prefix => f.write_str("Missing radix prefix"),
+            RadixError::Radix => f.write_str("Invalid radix"),
+            RadixError::WordSize => f.write_str("Invalid word size"),
+            RadixError::Digit => f.write_str("Invalid digit"),
+            RadixError::Overflow => f.write_str("Integer overflows"),
+        }
+    }
+}
+
+impl Error for RadixError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parses_prefixed_literals() {
+        assert_eq!(parse::<i32>("0xFf"), Ok(255));
+        assert_eq!(parse::<i32>("0o17"), Ok(15));
+        assert_eq!(parse::<i32>("-0b1010_1010"), Ok(-170));
+        assert_eq!(parse::<i32>("3#120"), Ok(15));
+        assert_eq!(parse::<i8>("-0x80"), Ok(i8::MIN));
+        assert_eq!(parse::<f64>("36#z"), Ok(35.));
+    }
+
+    #[test]
+    fn parse_errs_on_invalid_literals() {
+        assert_matches!(parse::<i32>("255"), Err(RadixError::Prefix));
+        assert_matches!(parse::<i32>("0b102"), Err(RadixError::Digit));
+        assert_matches!(parse::<i32>("37#1"), Err(RadixError::Radix));
+        assert_matches!(parse::<u8>("0x100"), Err(RadixError::Overflow));
+    }
+
+    #[test]
+    fn displays_in_radix() {
+        assert_eq!(RadixFormat::HEXADECIMAL.display(&255).to_string(), "0xFF");
+        assert_eq!(RadixFormat::BINARY.display(&-5).to_string(), "-0b101");
+        assert_eq!(
+            RadixFormat::new(3).unwrap().display(&15).to_string(),
+            "3#120"
+        );
+        assert_eq!(RadixFormat::OCTAL.display(&0u8).to_string(), "0o0");
+        assert_eq!(
+            RadixFormat::DECIMAL.display(&i128::MIN).to_string(),
+            i128::MIN.to_string()
+        );
+        let binary = RadixFormat::BINARY.display(&i128::MIN).to_string();
+        assert_eq!(binary.len(), 131);
+    }
+
+    #[test]
+    fn displays_twos_complement_at_word_size() {
+        let byte = RadixFormat::HEXADECIMAL.with_word_size(8).unwrap();
+        assert_eq!(byte.display(&-1).to_string(), "0xFF");
+        assert_eq!(byte.display(&0x1234).to_string(), "0x34");
+        let format = RadixFormat::OCTAL.with_word_size(64).unwrap();
+        assert_eq!(
+            format.display(&-1i8).to_string(),
+            "0o1777777777777777777777"
+        );
+        assert_eq!(format.display(&127i8).to_string(), "0o177");
+        let format = RadixFormat::BINARY.with_word_size(3).unwrap();
+        assert_eq!(format.display(&-2i64).to_string(), "0b110");
+        assert_matches!(
+            RadixFormat::DECIMAL.with_word_size(8),
+            Err(RadixError::Radix)
+        );
+    }
+}
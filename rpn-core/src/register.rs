@@ -0,0 +1,136 @@
+mod array;
+
+pub use array::ArrayRegisters;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Numbers stored by name outside of the stack, as the memory registers of calculators
+pub trait Registers {
+    type Item: Clone;
+
+    fn get(&self, name: &str) -> Option<&Self::Item>;
+    fn set(&mut self, name: &str, value: Self::Item) -> Result<(), RegisterError>;
+    fn clear(&mut self);
+    /// Registers holding a value, with their name
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a Self::Item)>
+    where
+        Self::Item: 'a;
+
+    /// Operation storing the top of the stack in the register, leaving it on the stack
+    fn store<S>(&mut self, name: &str, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = Self::Item>,
+    {
+        stack
+            .pop()?
+            .map(|value| {
+                self.set(name, value.clone())?;
+                Ok(value)
+            })?
+            .push()
+    }
+
+    /// Operation replacing the register with `f` of its value and the top of the stack, as
+    /// `sto+` adds to it, erring when the register is empty
+    fn store_with<S>(
+        &mut self,
+        name: &str,
+        stack: OpStack<S>,
+        f: impl FnOnce(Self::Item, Self::Item) -> Result<Self::Item, OperationError>,
+    ) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = Self::Item>,
+    {
+        stack
+            .pop()?
+            .map(|value| {
+                let stored = self.get(name).cloned().ok_or(RegisterError::Empty)?;
+                self.set(name, f(stored, value.clone())?)?;
+                Ok(value)
+            })?
+            .push()
+    }
+
+    /// Operation pushing the value of the register
+    fn recall<S>(&self, name: &str, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = Self::Item>,
+    {
+        let value = self.get(name).cloned().ok_or(RegisterError::Empty)?;
+        stack.hold(value).push()
+    }
+}
+
+#[derive(Debug)]
+pub enum RegisterError {
+    /// Nothing was stored in the register
+    Empty,
+    /// Every register already holds a value, the number of which is kept
+    Full(usize),
+    /// The name is empty or too long
+    Name,
+}
+
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            RegisterError::Empty => f.write_str("Empty register"),
+            RegisterError::Full(size) => f.write_fmt(format_args!("All {size} registers used")),
+            RegisterError::Name => f.write_str("Invalid register name"),
+        }
+    }
+}
+
+impl Error for RegisterError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Number;
+    use crate::stack::LargeStack;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn store_keeps_top_of_stack() {
+        let mut registers = ArrayRegisters::default();
+        let stack = LargeStack::<i32>::from_items(&[1, 2]);
+        let stack = stack.evaluate(|s| registers.store("a", s)).unwrap();
+        assert_eq!(stack.items(), [1, 2]);
+        assert_matches!(registers.get("a"), Some(2));
+    }
+
+    #[test]
+    fn store_with_combines_register_and_top_of_stack() {
+        let mut registers = ArrayRegisters::default();
+        registers.set("a", 10).unwrap();
+        let stack = LargeStack::<i32>::from_items(&[3]);
+        let stack = stack
+            .evaluate(|s| registers.store_with("a", s, Number::subtract))
+            .unwrap();
+        assert_eq!(stack.items(), [3]);
+        assert_matches!(registers.get("a"), Some(7));
+    }
+
+    #[test]
+    fn store_with_errs_on_empty_register() {
+        let mut registers = ArrayRegisters::<i32>::default();
+        let stack = LargeStack::from_items(&[3]);
+        let result = stack.evaluate(|s| registers.store_with("a", s, Number::add));
+        assert_matches!(result, Err(OperationError::Register(RegisterError::Empty)));
+        assert_matches!(registers.get("a"), None);
+    }
+
+    #[test]
+    fn recall_pushes_register() {
+        let mut registers = ArrayRegisters::default();
+        registers.set("b", 5).unwrap();
+        let stack = LargeStack::<i32>::from_items(&[1]);
+        let stack = stack.evaluate(|s| registers.recall("b", s)).unwrap();
+        assert_eq!(stack.items(), [1, 5]);
+        let result = stack.evaluate(|s| registers.recall("a", s));
+        assert_matches!(result, Err(OperationError::Register(RegisterError::Empty)));
+    }
+}
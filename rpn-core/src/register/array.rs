@@ -0,0 +1,130 @@
+use crate::register::{RegisterError, Registers};
+
+const MAX_REGISTERS: usize = 26;
+const MAX_NAME_LENGTH: usize = 8;
+
+/// Name of a register, kept inline as there is no allocation
+#[derive(Clone, Copy, Debug, Default)]
+struct Name {
+    bytes: [u8; MAX_NAME_LENGTH],
+    len: usize,
+}
+
+impl Name {
+    fn new(name: &str) -> Result<Self, RegisterError> {
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+            return Err(RegisterError::Name);
+        }
+        let mut bytes = [0; MAX_NAME_LENGTH];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Ok(Self {
+            bytes,
+            len: name.len(),
+        })
+    }
+
+    fn as_str(&self) -> &str {
+        // The bytes were copied from a `str`, so they are always valid
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+/// Fixed number of registers, in the order they were first stored to
+#[derive(Clone, Debug)]
+pub struct ArrayRegisters<N> {
+    registers: [(Name, N); MAX_REGISTERS],
+    count: usize,
+}
+
+impl<N: Default + Copy> Default for ArrayRegisters<N> {
+    fn default() -> Self {
+        Self {
+            registers: [(Name::default(), N::default()); MAX_REGISTERS],
+            count: 0,
+        }
+    }
+}
+
+impl<N: Copy> Registers for ArrayRegisters<N> {
+    type Item = N;
+
+    fn get(&self, name: &str) -> Option<&N> {
+        self.iter()
+            .find(|&(register, _)| register == name)
+            .map(|(_, value)| value)
+    }
+
+    fn set(&mut self, name: &str, value: N) -> Result<(), RegisterError> {
+        let name = Name::new(name)?;
+        let used = &mut self.registers[..self.count];
+        if let Some(register) = used.iter_mut().find(|(n, _)| n.as_str() == name.as_str()) {
+            register.1 = value;
+        } else if self.count == MAX_REGISTERS {
+            return Err(RegisterError::Full(MAX_REGISTERS));
+        } else {
+            self.registers[self.count] = (name, value);
+            self.count += 1;
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a N)>
+    where
+        N: 'a,
+    {
+        self.registers[..self.count]
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::assert_matches::assert_matches;
+
+    #[test]
+    fn set_replaces_value_of_register() {
+        let mut registers = ArrayRegisters::default();
+        registers.set("b", 1).unwrap();
+        registers.set("a", 2).unwrap();
+        registers.set("b", 3).unwrap();
+        let mut iter = registers.iter();
+        assert_matches!(iter.next(), Some(("b", 3)));
+        assert_matches!(iter.next(), Some(("a", 2)));
+        assert_matches!(iter.next(), None);
+    }
+
+    #[test]
+    fn set_errs_on_invalid_name() {
+        let mut registers = ArrayRegisters::default();
+        assert_matches!(registers.set("", 1), Err(RegisterError::Name));
+        assert_matches!(registers.set("too_long_", 1), Err(RegisterError::Name));
+        assert_matches!(registers.set("ok_long_", 1), Ok(()));
+    }
+
+    #[test]
+    fn set_errs_once_full() {
+        let mut registers = ArrayRegisters::default();
+        for (i, name) in [
+            "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m",
+        ]
+        .into_iter()
+        .chain([
+            "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+        ])
+        .enumerate()
+        {
+            registers.set(name, i).unwrap();
+        }
+        assert_matches!(registers.set("aa", 0), Err(RegisterError::Full(26)));
+        assert_matches!(registers.set("z", 0), Ok(()));
+        registers.clear();
+        assert_matches!(registers.get("a"), None);
+        assert_matches!(registers.set("aa", 0), Ok(()));
+    }
+}
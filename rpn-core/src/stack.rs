@@ -6,20 +6,40 @@ use core::fmt::{Display, Formatter};
 pub use large::LargeStack;
 pub use small::SmallStack;
 
-use crate::operation::{NoItems, OperationError, OperationStack};
+use crate::operation::{Context, NoItems, OperationError, OperationStack};
 
 pub trait Stack: Clone {
     type Item: Clone;
     
     fn size(&self) -> usize;
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a Self::Item>
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a Self::Item>
     where
         Self::Item: 'a;
     fn push(&mut self, value: Self::Item) -> Result<(), StackError>;
     fn pop(&mut self) -> Result<Self::Item, StackError>;
     
+    /// Reduces the top `count` items to one, from the top of the stack down as repeated binary
+    /// operations would, `f` taking the deeper item first
+    fn reduce(&self, count: usize, mut f: impl FnMut(Self::Item, Self::Item) -> Result<Self::Item, OperationError>) -> Result<Self::Item, OperationError> {
+        let depth = self.size();
+        if count == 0 || depth == 0 {
+            return Err(StackError::Empty.into());
+        }
+        if count > depth {
+            return Err(StackError::TooShallow(depth).into());
+        }
+        let mut items = self.iter().rev().take(count).cloned();
+        let top = items.next().ok_or(StackError::Empty)?;
+        items.try_fold(top, |reduced, item| f(item, reduced))
+    }
+
     fn evaluate(&self, operation: impl FnOnce(OperationStack<Self, NoItems>) -> Result<OperationStack<Self, NoItems>, OperationError>) -> Result<Self, OperationError> {
-        operation(OperationStack::new(self.clone())).map(OperationStack::stack)
+        self.evaluate_in(Context::default(), operation)
+    }
+
+    /// Evaluates the operation with the given settings, such as the angle mode
+    fn evaluate_in(&self, context: Context, operation: impl FnOnce(OperationStack<Self, NoItems>) -> Result<OperationStack<Self, NoItems>, OperationError>) -> Result<Self, OperationError> {
+        operation(OperationStack::new(self.clone(), context)).map(OperationStack::stack)
     }
 }
 
@@ -27,6 +47,8 @@ pub trait Stack: Clone {
 pub enum StackError {
     Empty,
     SizeExceeded(usize),
+    /// The operation needs more items than the stack holds, the number of which is kept
+    TooShallow(usize),
 }
 
 impl Display for StackError {
@@ -34,6 +56,7 @@ impl Display for StackError {
         match *self {
             StackError::Empty => f.write_str("Empty stack"),
             StackError::SizeExceeded(size) => f.write_fmt(format_args!("Size Exceeded: {size}")),
+            StackError::TooShallow(depth) => f.write_fmt(format_args!("Too shallow, only {depth} items")),
         }
     }
 }
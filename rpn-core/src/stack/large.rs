@@ -2,7 +2,7 @@ use crate::stack::{Stack, StackError};
 
 const MAX_STACK_SIZE: usize = 1024;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct LargeStack<N> {
     stack: [N; MAX_STACK_SIZE],
     top: usize,
@@ -17,6 +17,20 @@ impl<N: Default + Copy> Default for LargeStack<N> {
     }
 }
 
+#[cfg(test)]
+impl<N: Default + Copy> LargeStack<N> {
+    pub(crate) fn from_items(items: &[N]) -> Self {
+        let mut stack = Self::default();
+        stack.stack[..items.len()].copy_from_slice(items);
+        stack.top = items.len();
+        stack
+    }
+
+    pub(crate) fn items(&self) -> &[N] {
+        &self.stack[..self.top]
+    }
+}
+
 impl<N: Copy> Stack for LargeStack<N> {
     type Item = N;
     
@@ -24,7 +38,7 @@ impl<N: Copy> Stack for LargeStack<N> {
         self.top
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a N>
     where
         N: 'a,
     {
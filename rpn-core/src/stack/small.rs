@@ -46,7 +46,7 @@ impl<N: Copy + Debug> Stack for SmallStack<N> {
         }
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a N>
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a N>
     where
         N: 'a,
     {
@@ -113,3 +113,22 @@ impl<'a, N: Debug> Iterator for StackIterator<'a, N> {
         }
     }
 }
+
+impl<N: Debug> DoubleEndedIterator for StackIterator<'_, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match (self.a, self.b) {
+            (None, None) => None,
+            (Some(a), None) => {
+                self.a = &None;
+                Some(a)
+            }
+            (Some(_), Some(b)) => {
+                self.b = &None;
+                Some(b)
+            }
+            (None, Some(_b)) => {
+                unreachable!("invalid state within SmallStackEnvironment: (None, {_b:?})")
+            }
+        }
+    }
+}
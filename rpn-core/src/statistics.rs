@@ -0,0 +1,308 @@
+use crate::number::{Number, Power};
+use crate::operation::{OpStack, OperationError};
+use crate::stack::Stack;
+
+/// Summation registers of the data points accumulated so far, as the `Σ+` registers of HP
+/// calculators, from which the statistics of the points are computed
+#[derive(Clone, Debug)]
+pub struct Statistics<N> {
+    count: N,
+    sum_x: N,
+    sum_x2: N,
+    sum_y: N,
+    sum_y2: N,
+    sum_xy: N,
+}
+
+/// Whether the data points are a sample of the population or the whole of it, which changes
+/// the divisor of the variance from `n - 1` to `n`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deviation {
+    Sample,
+    Population,
+}
+
+impl<N: Number> Default for Statistics<N> {
+    fn default() -> Self {
+        Self {
+            count: N::ZERO,
+            sum_x: N::ZERO,
+            sum_x2: N::ZERO,
+            sum_y: N::ZERO,
+            sum_y2: N::ZERO,
+            sum_xy: N::ZERO,
+        }
+    }
+}
+
+impl<N: Number> Statistics<N> {
+    /// Number of data points, `n`
+    pub fn count(&self) -> &N {
+        &self.count
+    }
+
+    /// `Σx`
+    pub fn sum_x(&self) -> &N {
+        &self.sum_x
+    }
+
+    /// `Σx²`
+    pub fn sum_x2(&self) -> &N {
+        &self.sum_x2
+    }
+
+    /// `Σy`
+    pub fn sum_y(&self) -> &N {
+        &self.sum_y
+    }
+
+    /// `Σy²`
+    pub fn sum_y2(&self) -> &N {
+        &self.sum_y2
+    }
+
+    /// `Σxy`
+    pub fn sum_xy(&self) -> &N {
+        &self.sum_xy
+    }
+
+    /// Adds a data point, leaving the registers unchanged when any of them would overflow
+    pub fn add(&mut self, x: N, y: N) -> Result<(), OperationError> {
+        *self = self.clone().updated(x, y, Number::add)?;
+        Ok(())
+    }
+
+    /// Removes a data point added by mistake
+    pub fn remove(&mut self, x: N, y: N) -> Result<(), OperationError> {
+        *self = self.clone().updated(x, y, Number::subtract)?;
+        Ok(())
+    }
+
+    fn updated(
+        self,
+        x: N,
+        y: N,
+        f: fn(N, N) -> Result<N, OperationError>,
+    ) -> Result<Self, OperationError> {
+        Ok(Self {
+            count: f(self.count, N::ONE)?,
+            sum_x: f(self.sum_x, x.clone())?,
+            sum_x2: f(self.sum_x2, x.clone().multiply(x.clone())?)?,
+            sum_y: f(self.sum_y, y.clone())?,
+            sum_y2: f(self.sum_y2, y.clone().multiply(y.clone())?)?,
+            sum_xy: f(self.sum_xy, x.multiply(y)?)?,
+        })
+    }
+
+    /// Operation adding the `x` on top of the stack as a data point of a single variable, its
+    /// `y` being zero
+    pub fn accumulate<S>(&mut self, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = N>,
+    {
+        stack.pop()?.consume(|x| self.add(x, N::ZERO))
+    }
+
+    /// Operation adding the data point whose `x` is on top of the stack and `y` below it
+    pub fn accumulate_pair<S>(&mut self, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = N>,
+    {
+        stack
+            .pop()?
+            .pop()?
+            .combine(|y, x| Ok((x, y)))?
+            .consume(|(x, y)| self.add(x, y))
+    }
+
+    /// Operation removing the data point of a single variable on top of the stack
+    pub fn deduct<S>(&mut self, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = N>,
+    {
+        stack.pop()?.consume(|x| self.remove(x, N::ZERO))
+    }
+
+    /// Operation removing the data point popped as by [`Self::accumulate_pair`]
+    pub fn deduct_pair<S>(&mut self, stack: OpStack<S>) -> Result<OpStack<S>, OperationError>
+    where
+        S: Stack<Item = N>,
+    {
+        stack
+            .pop()?
+            .pop()?
+            .combine(|y, x| Ok((x, y)))?
+            .consume(|(x, y)| self.remove(x, y))
+    }
+
+    /// `x̄`
+    pub fn mean_x(&self) -> Result<N, OperationError> {
+        self.sum_x.clone().divide(self.count.clone())
+    }
+
+    /// `ȳ`
+    pub fn mean_y(&self) -> Result<N, OperationError> {
+        self.sum_y.clone().divide(self.count.clone())
+    }
+
+    /// Sum of the products of the deviations from the means, `Σab - ΣaΣb / n`
+    fn deviations(&self, sum_ab: &N, sum_a: &N, sum_b: &N) -> Result<N, OperationError> {
+        let product = sum_a.clone().multiply(sum_b.clone())?;
+        sum_ab.clone().subtract(product.divide(self.count.clone())?)
+    }
+
+    /// Variance of `x`
+    pub fn variance(&self, deviation: Deviation) -> Result<N, OperationError> {
+        let divisor = match deviation {
+            Deviation::Sample => self.count.clone().subtract(N::ONE)?,
+            Deviation::Population => self.count.clone(),
+        };
+        self.deviations(&self.sum_x2, &self.sum_x, &self.sum_x)?
+            .divide(divisor)
+    }
+
+    /// Slope of the least squares line of `y` against `x`
+    pub fn slope(&self) -> Result<N, OperationError> {
+        let sxy = self.deviations(&self.sum_xy, &self.sum_x, &self.sum_y)?;
+        sxy.divide(self.deviations(&self.sum_x2, &self.sum_x, &self.sum_x)?)
+    }
+
+    /// Value of `y` where the least squares line crosses `x = 0`
+    pub fn intercept(&self) -> Result<N, OperationError> {
+        let slope = self.slope()?;
+        self.mean_y()?.subtract(slope.multiply(self.mean_x()?)?)
+    }
+
+    /// `ŷ`, the value of `y` the least squares line predicts for `x`
+    pub fn predict(&self, x: N) -> Result<N, OperationError> {
+        self.intercept()?.add(self.slope()?.multiply(x)?)
+    }
+}
+
+impl<N: Power> Statistics<N> {
+    /// Standard deviation of `x`
+    pub fn standard_deviation(&self, deviation: Deviation) -> Result<N, OperationError> {
+        self.variance(deviation)?.sqrt()
+    }
+
+    /// Correlation coefficient of `x` and `y`, from -1 to 1
+    pub fn correlation(&self) -> Result<N, OperationError> {
+        let sxx = self.deviations(&self.sum_x2, &self.sum_x, &self.sum_x)?;
+        let syy = self.deviations(&self.sum_y2, &self.sum_y, &self.sum_y)?;
+        let sxy = self.deviations(&self.sum_xy, &self.sum_x, &self.sum_y)?;
+        sxy.divide(sxx.multiply(syy)?.sqrt()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{NumberError, Rational};
+    use crate::stack::{LargeStack, StackError};
+    use core::assert_matches::assert_matches;
+
+    fn statistics(points: &[(f64, f64)]) -> Statistics<f64> {
+        let mut statistics = Statistics::default();
+        for &(x, y) in points {
+            statistics.add(x, y).unwrap();
+        }
+        statistics
+    }
+
+    #[test]
+    fn computes_statistics_of_one_variable() {
+        let statistics = statistics(&[(2., 0.), (4., 0.), (4., 0.), (6., 0.)]);
+        assert_eq!(statistics.mean_x().unwrap(), 4.);
+        assert_eq!(statistics.variance(Deviation::Population).unwrap(), 2.);
+        assert_eq!(statistics.variance(Deviation::Sample).unwrap(), 8. / 3.);
+        let deviation = statistics.standard_deviation(Deviation::Population);
+        assert_eq!(deviation.unwrap(), 2f64.sqrt());
+    }
+
+    #[test]
+    fn computes_linear_regression() {
+        let statistics = statistics(&[(1., 3.), (2., 5.), (3., 7.)]);
+        assert_eq!(statistics.slope().unwrap(), 2.);
+        assert_eq!(statistics.intercept().unwrap(), 1.);
+        assert_eq!(statistics.predict(10.).unwrap(), 21.);
+        assert_eq!(statistics.correlation().unwrap(), 1.);
+    }
+
+    #[test]
+    fn computes_exact_statistics_of_rationals() {
+        let rational = |n| Rational::<i64>::from(n);
+        let mut statistics = Statistics::default();
+        for n in [1, 2, 4] {
+            statistics.add(rational(n), rational(0)).unwrap();
+        }
+        assert_eq!(statistics.mean_x().unwrap(), Rational::new(7, 3).unwrap());
+        assert_eq!(
+            statistics.variance(Deviation::Sample).unwrap(),
+            Rational::new(7, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn remove_undoes_add() {
+        let mut statistics = statistics(&[(1., 3.), (2., 5.)]);
+        statistics.add(8., 1.).unwrap();
+        statistics.remove(8., 1.).unwrap();
+        assert_eq!(*statistics.count(), 2.);
+        assert_eq!(statistics.slope().unwrap(), 2.);
+    }
+
+    #[test]
+    fn add_leaves_registers_unchanged_on_overflow() {
+        let mut statistics = Statistics::<i32>::default();
+        statistics.add(2, 3).unwrap();
+        let result = statistics.add(100_000, 1);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::Overflow(_)))
+        );
+        assert_eq!(*statistics.count(), 1);
+        assert_eq!(*statistics.sum_x2(), 4);
+    }
+
+    #[test]
+    fn statistics_err_without_enough_points() {
+        let statistics = statistics(&[(1., 1.)]);
+        assert_matches!(
+            statistics.variance(Deviation::Sample),
+            Err(OperationError::Number(_))
+        );
+    }
+
+    #[test]
+    fn accumulate_pops_single_variable() {
+        let mut statistics = Statistics::default();
+        let stack = LargeStack::<f64>::from_items(&[3., 4., 4.]);
+        let stack = stack.evaluate(|s| statistics.accumulate(s)).unwrap();
+        assert_eq!(stack.items(), [3., 4.]);
+        assert_eq!(*statistics.count(), 1.);
+        assert_eq!(*statistics.sum_x(), 4.);
+        assert_eq!(*statistics.sum_x2(), 16.);
+        assert_eq!(*statistics.sum_y(), 0.);
+        let stack = stack.evaluate(|s| statistics.deduct(s)).unwrap();
+        assert_eq!(stack.items(), [3.]);
+        assert_eq!(*statistics.count(), 0.);
+        assert_eq!(*statistics.sum_x(), 0.);
+        assert_eq!(*statistics.sum_x2(), 0.);
+        assert_eq!(*statistics.sum_y(), 0.);
+        assert_eq!(*statistics.sum_y2(), 0.);
+        assert_eq!(*statistics.sum_xy(), 0.);
+    }
+
+    #[test]
+    fn accumulate_pair_pops_x_then_y() {
+        let mut statistics = Statistics::default();
+        let stack = LargeStack::<f64>::from_items(&[3., 1., 4.]);
+        let stack = stack.evaluate(|s| statistics.accumulate_pair(s)).unwrap();
+        assert_eq!(stack.items(), [3.]);
+        assert_eq!(*statistics.sum_x(), 4.);
+        assert_eq!(*statistics.sum_y(), 1.);
+        let result = stack.evaluate(|s| statistics.accumulate_pair(s));
+        assert_matches!(result, Err(OperationError::Stack(StackError::Empty)));
+        assert_eq!(*statistics.count(), 1.);
+    }
+}
@@ -1,2 +1,5 @@
 #![cfg_attr(test, feature(assert_matches))]
+pub mod notation;
+pub mod number;
+pub mod register;
 pub mod stack;
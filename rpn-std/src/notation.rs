@@ -0,0 +1,127 @@
+use rpn_core::number::Number;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Prefixes of the SI units, with the power of ten they stand for
+const SI_PREFIXES: [(char, i32); 13] = [
+    ('f', -15),
+    ('p', -12),
+    ('n', -9),
+    ('µ', -6),
+    ('u', -6),
+    ('m', -3),
+    ('k', 3),
+    ('M', 6),
+    ('G', 9),
+    ('T', 12),
+    ('P', 15),
+    ('E', 18),
+    ('Z', 21),
+];
+
+/// How floats are written out
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Shortest digits reading back as the same float
+    #[default]
+    Standard,
+    /// Given number of digits after the decimal point, as in `1234.50`
+    Fixed(usize),
+    /// One digit before the decimal point and the given number of significant digits,
+    /// as in `1.23e3`
+    Scientific(usize),
+    /// Exponent a multiple of three and the given number of significant digits, as in `12.3e3`
+    Engineering(usize),
+}
+
+impl Notation {
+    pub fn format(&self, value: f64) -> String {
+        match *self {
+            _ if !value.is_finite() => value.to_string(),
+            Notation::Standard => value.to_string(),
+            Notation::Fixed(digits) => format!("{value:.digits$}"),
+            Notation::Scientific(digits) => {
+                let precision = digits.max(1) - 1;
+                format!("{value:.precision$e}")
+            }
+            Notation::Engineering(digits) => engineering(value, digits.max(1)),
+        }
+    }
+}
+
+/// Moves the decimal point of the scientific notation right until the exponent is a multiple
+/// of three, padding with zeros when there are not enough significant digits
+fn engineering(value: f64, digits: usize) -> String {
+    let scientific = format!("{:.precision$e}", value.abs(), precision = digits - 1);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or_default();
+    let shift = exponent.rem_euclid(3) as usize;
+    let mut mantissa: String = mantissa.chars().filter(|&c| c != '.').collect();
+    while mantissa.len() < shift + 1 {
+        mantissa.push('0');
+    }
+    if mantissa.len() > shift + 1 {
+        mantissa.insert(shift + 1, '.');
+    }
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    format!("{sign}{mantissa}e{}", exponent - shift as i32)
+}
+
+impl Display for Notation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Notation::Standard => f.write_str("STD"),
+            Notation::Fixed(digits) => write!(f, "FIX {digits}"),
+            Notation::Scientific(digits) => write!(f, "SCI {digits}"),
+            Notation::Engineering(digits) => write!(f, "ENG {digits}"),
+        }
+    }
+}
+
+/// Parses a number, also accepting one followed by an SI prefix such as `1.5k` or `20µ`, but
+/// not one whose prefix would truncate it, as `5m` would to an integer
+pub fn parse<N: FromStr + Number>(s: &str) -> Option<N> {
+    if let Ok(n) = s.parse() {
+        return Some(n);
+    }
+    let prefix = s.chars().next_back()?;
+    let &(_, exponent) = SI_PREFIXES.iter().find(|&&(p, _)| p == prefix)?;
+    let n: N = s[..s.len() - prefix.len_utf8()].parse().ok()?;
+    let truncating = N::ONE.divide(N::TEN).is_ok_and(|tenth| tenth == N::ZERO);
+    (0..exponent.unsigned_abs()).try_fold(n, |n, _| match exponent > 0 {
+        true => n.multiply(N::TEN).ok(),
+        false if truncating && n.clone().remainder(N::TEN).ok()? != N::ZERO => None,
+        false => n.divide(N::TEN).ok(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_in_every_notation() {
+        assert_eq!(Notation::Standard.format(1234.5), "1234.5");
+        assert_eq!(Notation::Fixed(2).format(1234.5), "1234.50");
+        assert_eq!(Notation::Scientific(3).format(1234.5), "1.23e3");
+        assert_eq!(Notation::Scientific(3).format(-0.00012), "-1.20e-4");
+        assert_eq!(Notation::Engineering(3).format(1234.5), "1.23e3");
+        assert_eq!(Notation::Engineering(3).format(12345.), "12.3e3");
+        assert_eq!(Notation::Engineering(2).format(-0.00012), "-120e-6");
+        assert_eq!(Notation::Engineering(1).format(600.), "600e0");
+        assert_eq!(Notation::Engineering(3).format(f64::INFINITY), "inf");
+    }
+
+    #[test]
+    fn parses_si_prefixes() {
+        assert_eq!(parse::<f64>("6.02e23"), Some(6.02e23));
+        assert_eq!(parse::<f64>("1.5k"), Some(1500.));
+        assert_eq!(parse::<f64>("20µ"), Some(0.00002));
+        assert_eq!(parse::<i32>("3M"), Some(3_000_000));
+        assert_eq!(parse::<i32>("3x"), None);
+        assert_eq!(parse::<i32>("5m"), None);
+        assert_eq!(parse::<i32>("5000m"), Some(5));
+        assert_eq!(parse::<f64>("5m"), Some(0.005));
+        assert_eq!(parse::<f64>("k"), None);
+    }
+}
@@ -0,0 +1,5 @@
+mod big_integer;
+mod big_rational;
+
+pub use big_integer::{BigInt, ParseBigIntError};
+pub use big_rational::BigRational;
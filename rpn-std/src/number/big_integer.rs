@@ -0,0 +1,383 @@
+use rpn_core::number::{Number, NumberError};
+use rpn_core::operation::OperationError;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Largest power of ten fitting in a limb, used to convert from and to decimal strings
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+/// Arbitrary-precision integer stored as a sign and a magnitude of base 2^32 limbs,
+/// least significant limb first and without trailing zero limbs, so zero has no limbs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Cow<'static, [u32]>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude: Cow::Owned(magnitude),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn negate(self) -> Self {
+        Self {
+            negative: !self.negative && !self.is_zero(),
+            magnitude: self.magnitude,
+        }
+    }
+
+    /// Quotient truncated toward zero along with the remainder, which takes the sign of `self`
+    fn divide_remainder(self, other: Self) -> Result<(Self, Self), OperationError> {
+        if other.is_zero() {
+            return Err(NumberError::DivisionByZero.into());
+        }
+        let (quotient, remainder) = divide_magnitude(&self.magnitude, &other.magnitude);
+        Ok((
+            Self::from_parts(self.negative != other.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        ))
+    }
+}
+
+impl Number for BigInt {
+    const ZERO: Self = Self {
+        negative: false,
+        magnitude: Cow::Borrowed(&[]),
+    };
+    const ONE: Self = Self {
+        negative: false,
+        magnitude: Cow::Borrowed(&[1]),
+    };
+    const TEN: Self = Self {
+        negative: false,
+        magnitude: Cow::Borrowed(&[10]),
+    };
+
+    fn add(self, other: Self) -> Result<Self, OperationError> {
+        if self.negative == other.negative {
+            return Ok(Self::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            ));
+        }
+        Ok(match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => Self::from_parts(
+                other.negative,
+                subtract_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => Self::from_parts(
+                self.negative,
+                subtract_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        })
+    }
+
+    fn subtract(self, other: Self) -> Result<Self, OperationError> {
+        self.add(other.negate())
+    }
+
+    fn multiply(self, other: Self) -> Result<Self, OperationError> {
+        Ok(Self::from_parts(
+            self.negative != other.negative,
+            multiply_magnitude(&self.magnitude, &other.magnitude),
+        ))
+    }
+
+    fn divide(self, other: Self) -> Result<Self, OperationError> {
+        self.divide_remainder(other).map(|(quotient, _)| quotient)
+    }
+
+    fn remainder(self, other: Self) -> Result<Self, OperationError> {
+        self.divide_remainder(other).map(|(_, remainder)| remainder)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.to_vec();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = divide_limb(&magnitude, DECIMAL_BASE);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:0DECIMAL_DIGITS$}"));
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError::InvalidDigit);
+        }
+        let mut magnitude = Vec::new();
+        let first = match digits.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            n => n,
+        };
+        let mut start = 0;
+        let mut end = first;
+        while start < digits.len() {
+            let chunk = &digits[start..end];
+            let value = chunk.parse().map_err(|_| ParseBigIntError::InvalidDigit)?;
+            magnitude = multiply_add_limb(&magnitude, 10u32.pow(chunk.len() as u32), value);
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseBigIntError {
+    Empty,
+    InvalidDigit,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParseBigIntError::Empty => f.write_str("Empty integer"),
+            ParseBigIntError::InvalidDigit => f.write_str("Invalid digit in integer"),
+        }
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+macro_rules! impl_from_integer {
+    ($T:ty, $to_parts:expr) => {
+        impl From<$T> for BigInt {
+            fn from(value: $T) -> Self {
+                let (negative, mut magnitude): (bool, u128) = $to_parts(value);
+                let mut limbs = Vec::new();
+                while magnitude != 0 {
+                    limbs.push(magnitude as u32);
+                    magnitude >>= 32;
+                }
+                Self::from_parts(negative, limbs)
+            }
+        }
+    };
+}
+
+impl_from_integer!(u8, |v| (false, v as u128));
+impl_from_integer!(u16, |v| (false, v as u128));
+impl_from_integer!(u32, |v| (false, v as u128));
+impl_from_integer!(u64, |v| (false, v as u128));
+impl_from_integer!(u128, |v| (false, v));
+impl_from_integer!(i8, |v: i8| (v < 0, v.unsigned_abs() as u128));
+impl_from_integer!(i16, |v: i16| (v < 0, v.unsigned_abs() as u128));
+impl_from_integer!(i32, |v: i32| (v < 0, v.unsigned_abs() as u128));
+impl_from_integer!(i64, |v: i64| (v < 0, v.unsigned_abs() as u128));
+impl_from_integer!(i128, |v: i128| (v < 0, v.unsigned_abs()));
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry != 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Subtracts `b` from `a`, which must have the larger magnitude
+fn subtract_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut difference = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        }
+        result.push(difference as u32);
+    }
+    result
+}
+
+fn multiply_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    result
+}
+
+fn multiply_add_limb(a: &[u32], factor: u32, addend: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = addend as u64;
+    for &limb in a {
+        let product = limb as u64 * factor as u64 + carry;
+        result.push(product as u32);
+        carry = product >> 32;
+    }
+    if carry != 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+fn divide_limb(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for (i, &limb) in a.iter().enumerate().rev() {
+        let current = (remainder << 32) | limb as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    (quotient, remainder as u32)
+}
+
+/// Long division of magnitudes, one bit of the dividend at a time; `b` must not be zero
+fn divide_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if let [divisor] = b {
+        let (quotient, remainder) = divide_limb(a, *divisor);
+        return (quotient, vec![remainder]);
+    }
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = Vec::with_capacity(b.len() + 1);
+    for bit in (0..a.len() * 32).rev() {
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            remainder.push(carry);
+        }
+        if compare_magnitude(&remainder, b) != Ordering::Less {
+            remainder = subtract_magnitude(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::assert_matches::assert_matches;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_large_values() {
+        let digits = "-123456789012345678901234567890123456789";
+        assert_eq!(big(digits).to_string(), digits);
+        assert_eq!(big("+000").to_string(), "0");
+        assert_matches!("12a".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+    }
+
+    #[test]
+    fn multiply_does_not_overflow() {
+        let factorial = (1..=30).fold(BigInt::ONE, |acc, n| acc.multiply(n.into()).unwrap());
+        assert_eq!(factorial, big("265252859812191058636308480000000"));
+    }
+
+    #[test]
+    fn add_and_subtract_handle_signs() {
+        assert_eq!(big("-5").add(big("3")).unwrap(), big("-2"));
+        assert_eq!(big("3").subtract(big("5")).unwrap(), big("-2"));
+        assert_eq!(big("-3").subtract(big("-3")).unwrap(), BigInt::ZERO);
+    }
+
+    #[test]
+    fn divide_truncates_like_primitive_integers() {
+        let dividend = big("-100000000000000000000000000007");
+        let divisor = big("10000000000000000000000000000");
+        assert_eq!(dividend.clone().divide(divisor.clone()).unwrap(), big("-10"));
+        assert_eq!(dividend.remainder(divisor).unwrap(), big("-7"));
+    }
+
+    #[test]
+    fn divide_errs_on_division_by_zero() {
+        let result = big("1").divide(BigInt::ZERO);
+        assert_matches!(
+            result,
+            Err(OperationError::Number(NumberError::DivisionByZero))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::number::BigInt;
+use rpn_core::number::Rational;
+
+/// Exact fraction of unbounded size, normalized after every operation.
+///
+/// Remainders follow truncated division like primitive integers do,
+/// so `a % b` has the sign of `a` and satisfies `a == b * trunc(a / b) + a % b`.
+/// Use [`Rational::decimal_expansion`] to display it in decimal notation.
+pub type BigRational = Rational<BigInt>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpn_core::number::Number;
+
+    fn big(s: &str) -> BigRational {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn arithmetic_never_overflows() {
+        let value = big("1/99999999999999999999")
+            .multiply(big("1/99999999999999999999"))
+            .unwrap();
+        assert_eq!(
+            value.to_string(),
+            "1/9999999999999999999800000000000000000001"
+        );
+    }
+
+    #[test]
+    fn remainder_has_sign_of_dividend() {
+        let value = big("-7/2").remainder(big("3/2")).unwrap();
+        assert_eq!(value, big("-1/2"));
+    }
+
+    #[test]
+    fn displays_requested_decimal_digits() {
+        let value = big("-22/7");
+        assert_eq!(value.decimal_expansion(5).to_string(), "-3.14285");
+    }
+}
@@ -0,0 +1,79 @@
+use rpn_core::register::{RegisterError, Registers};
+use std::collections::BTreeMap;
+
+/// Unbounded registers, in order of name
+#[derive(Clone, Debug)]
+pub struct MapRegisters<N> {
+    registers: BTreeMap<String, N>,
+}
+
+impl<N> Default for MapRegisters<N> {
+    fn default() -> Self {
+        Self {
+            registers: BTreeMap::new(),
+        }
+    }
+}
+
+impl<N: Clone> Registers for MapRegisters<N> {
+    type Item = N;
+
+    fn get(&self, name: &str) -> Option<&N> {
+        self.registers.get(name)
+    }
+
+    fn set(&mut self, name: &str, value: N) -> Result<(), RegisterError> {
+        if name.is_empty() {
+            return Err(RegisterError::Name);
+        }
+        self.registers.insert(name.to_owned(), value);
+        Ok(())
+    }
+
+    fn clear(&mut self) {
+        self.registers.clear();
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a N)>
+    where
+        N: 'a,
+    {
+        self.registers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::assert_matches::assert_matches;
+
+    #[test]
+    fn set_replaces_value_of_register() {
+        let mut registers = MapRegisters::default();
+        registers.set("b", 1).unwrap();
+        registers.set("a", 2).unwrap();
+        registers.set("b", 3).unwrap();
+        let mut iter = registers.iter();
+        assert_matches!(iter.next(), Some(("a", 2)));
+        assert_matches!(iter.next(), Some(("b", 3)));
+        assert_matches!(iter.next(), None);
+    }
+
+    #[test]
+    fn set_errs_on_empty_name() {
+        let mut registers = MapRegisters::default();
+        assert_matches!(registers.set("", 1), Err(RegisterError::Name));
+        assert_matches!(registers.set("a_long_register_name", 1), Ok(()));
+    }
+
+    #[test]
+    fn clear_empties_registers() {
+        let mut registers = MapRegisters::default();
+        registers.set("a", 1).unwrap();
+        registers.clear();
+        assert_matches!(registers.get("a"), None);
+        assert_matches!(registers.iter().next(), None);
+    }
+}
@@ -18,7 +18,7 @@ impl<N: Clone> Stack for VecStack<N> {
         self.stack.len()
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item=&'a N>
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item=&'a N>
     where
         N: 'a
     {
@@ -1,22 +1,37 @@
+use rpn_core::number::Number;
 use rpn_core::operation::{
-    add, copy, divide, multiply, remainder, rotate, subtract, OpStack, OperationError,
+    abs, add, clear, copy, depth, divide, drop, dropn, dupn, mean, multiply, negate, nip, over,
+    pick, power, product, remainder, roll, roll_down, rot3, rotate, sqrt, subtract, sum, tuck,
+    Context, OpStack, OperationError,
 };
+use rpn_core::radix::{self, RadixError, RadixFormat};
+use rpn_core::register::Registers;
 use rpn_core::stack::Stack;
+use rpn_std::notation::{self, Notation};
+use rpn_std::register::MapRegisters;
 use rpn_std::stack::VecStack;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast;
 use web_sys::{console, window, Element, HtmlInputElement, InputEvent};
 
 type S = VecStack<i32>;
+type RegisterOperation =
+    fn(&mut MapRegisters<i32>, &str, OpStack<S>) -> Result<OpStack<S>, OperationError>;
 
 #[wasm_bindgen]
 pub struct WasmEnvironment {
     stack: S,
     history: Vec<String>,
+    context: Context,
+    radix: RadixFormat,
+    notation: Notation,
+    registers: MapRegisters<i32>,
 
     input_element: HtmlInputElement,
     stack_element: Element,
+    registers_element: Element,
     history_element: Element,
+    mode_element: Element,
 }
 
 #[allow(clippy::new_without_default)]
@@ -29,6 +44,10 @@ impl WasmEnvironment {
         Self {
             stack: VecStack::default(),
             history: Vec::new(),
+            context: Context::default(),
+            radix: RadixFormat::default(),
+            notation: Notation::default(),
+            registers: MapRegisters::default(),
             input_element: document
                 .get_element_by_id("input")
                 .expect("no input element found")
@@ -37,16 +56,29 @@ impl WasmEnvironment {
             stack_element: document
                 .get_element_by_id("stack")
                 .expect("no stack element found"),
+            registers_element: document
+                .get_element_by_id("registers")
+                .expect("no registers element found"),
             history_element: document
                 .get_element_by_id("history")
                 .expect("no history element found"),
+            mode_element: document
+                .get_element_by_id("mode")
+                .expect("no mode element found"),
         }
     }
 
     fn clear_print(&self) {
         self.input_element.set_value("");
         self.print_stack();
+        self.print_registers();
         self.print_history();
+        self.print_mode();
+    }
+
+    fn print_mode(&self) {
+        self.mode_element
+            .set_text_content(Some(&format!("{} {}", self.radix, self.notation)));
     }
 
     fn print_stack(&self) {
@@ -55,11 +87,35 @@ impl WasmEnvironment {
                 .stack
                 .iter()
                 .enumerate()
-                .map(|(index, item)| format!("<tr><td>{index}</td><td>{item}</td></tr>"))
+                .map(|(index, item)| {
+                    format!("<tr><td>{index}</td><td>{}</td></tr>", self.format(item))
+                })
+                .collect::<String>(),
+        );
+    }
+
+    fn print_registers(&self) {
+        self.registers_element.set_inner_html(
+            &self
+                .registers
+                .iter()
+                .map(|(name, item)| {
+                    format!("<tr><td>{name}</td><td>{}</td></tr>", self.format(item))
+                })
                 .collect::<String>(),
         );
     }
 
+    fn format(&self, item: &i32) -> String {
+        match self.notation {
+            // Notations only apply to decimal display
+            notation if self.radix == RadixFormat::DECIMAL && notation != Notation::Standard => {
+                notation.format(f64::from(*item))
+            }
+            _ => self.radix.display(item).to_string(),
+        }
+    }
+
     fn print_history(&self) {
         self.history_element.set_inner_html(
             &self
@@ -72,7 +128,12 @@ impl WasmEnvironment {
 
     #[wasm_bindgen]
     pub fn evaluate(&mut self, event: InputEvent) {
-        let action = match event.data().and_then(|s| s.chars().next()) {
+        let typed = event.data().and_then(|s| s.chars().next());
+        let action = match typed {
+            // Letters are digits rather than commands while typing a radix literal
+            Some(c) if self.literal_radix().is_some_and(|radix| c.is_digit(radix) || c == '_') => {
+                WasmInputAction::Ignore
+            }
             Some(' ') => WasmInputAction::Push,
             Some('+') => WasmInputAction::Operation(add, "+"),
             Some('-') => WasmInputAction::Operation(subtract, "-"),
@@ -81,6 +142,48 @@ impl WasmEnvironment {
             Some('%') => WasmInputAction::Operation(remainder, "%"),
             Some('r') => WasmInputAction::Operation(rotate, "rotate"),
             Some('c') => WasmInputAction::Operation(copy, "copy"),
+            Some('d') => WasmInputAction::Operation(drop, "drop"),
+            Some('K') => WasmInputAction::Operation(clear, "clear"),
+            Some('v') => WasmInputAction::Operation(over, "over"),
+            Some('i') => WasmInputAction::Operation(nip, "nip"),
+            Some('t') => WasmInputAction::Operation(tuck, "tuck"),
+            Some('p') => WasmInputAction::Operation(pick, "pick"),
+            Some('R') => WasmInputAction::Operation(roll, "roll"),
+            Some('Q') => WasmInputAction::Operation(roll_down, "rolld"),
+            Some('w') => WasmInputAction::Operation(rot3, "rot3"),
+            Some('u') => WasmInputAction::Operation(dupn, "dupn"),
+            Some('z') => WasmInputAction::Operation(dropn, "dropn"),
+            Some('?') => WasmInputAction::Operation(depth, "depth"),
+            Some('S') => WasmInputAction::Operation(sum, "sum"),
+            Some('P') => WasmInputAction::Operation(product, "product"),
+            Some('V') => WasmInputAction::Operation(mean, "mean"),
+            Some('n') => WasmInputAction::Operation(negate, "negate"),
+            Some('a') => WasmInputAction::Operation(abs, "abs"),
+            Some('^') => WasmInputAction::Operation(power, "^"),
+            Some('s') => WasmInputAction::Operation(sqrt, "sqrt"),
+            Some('>') => WasmInputAction::Register(|r, name, stack| r.store(name, stack), "sto"),
+            Some('<') => WasmInputAction::Register(|r, name, stack| r.recall(name, stack), "rcl"),
+            Some('}') => WasmInputAction::Register(
+                |r, name, stack| r.store_with(name, stack, Number::add),
+                "sto+",
+            ),
+            Some('{') => WasmInputAction::Register(
+                |r, name, stack| r.store_with(name, stack, Number::subtract),
+                "sto-",
+            ),
+            Some(')') => WasmInputAction::Register(
+                |r, name, stack| r.store_with(name, stack, Number::multiply),
+                "sto*",
+            ),
+            Some('(') => WasmInputAction::Register(
+                |r, name, stack| r.store_with(name, stack, Number::divide),
+                "sto/",
+            ),
+            Some('X') => WasmInputAction::ClearRegisters,
+            Some('h') => WasmInputAction::CycleRadix,
+            Some('f') => WasmInputAction::CycleNotation,
+            Some('e') | Some('k') | Some('M') | Some('G') => WasmInputAction::Ignore,
+            Some('x') | Some('o') | Some('b') | Some('#') => WasmInputAction::Ignore,
             Some('0') | Some('1') | Some('2') | Some('3') | Some('4') | Some('5') | Some('6')
             | Some('7') | Some('8') | Some('9') => WasmInputAction::Ignore,
             Some(c) => WasmInputAction::Unknown(c),
@@ -88,18 +191,44 @@ impl WasmEnvironment {
         };
         match action {
             WasmInputAction::Push => {
-                self.push_from_input();
+                self.push_from_input(typed);
                 self.clear_print();
             }
             WasmInputAction::Ignore => {
             }
+            WasmInputAction::CycleRadix => {
+                self.radix = match self.radix {
+                    RadixFormat::DECIMAL => RadixFormat::HEXADECIMAL,
+                    RadixFormat::HEXADECIMAL => RadixFormat::OCTAL,
+                    RadixFormat::OCTAL => RadixFormat::BINARY,
+                    _ => RadixFormat::DECIMAL,
+                };
+                self.clear_print();
+            }
+            WasmInputAction::CycleNotation => {
+                self.notation = match self.notation {
+                    Notation::Standard => Notation::Fixed(2),
+                    Notation::Fixed(_) => Notation::Scientific(3),
+                    Notation::Scientific(_) => Notation::Engineering(3),
+                    Notation::Engineering(_) => Notation::Standard,
+                };
+                self.clear_print();
+            }
+            WasmInputAction::Register(o, name) => {
+                self.evaluate_register(o, name, typed);
+            }
+            WasmInputAction::ClearRegisters => {
+                self.registers.clear();
+                self.history.push("regclr".into());
+                self.clear_print();
+            }
             WasmInputAction::Unknown(c) => {
                 console::error_1(&format!("unknown operation {c}").into());
                 self.clear_print();
             }
             WasmInputAction::Operation(o, name) => {
-                self.push_from_input();
-                match self.stack.evaluate(o) {
+                self.push_from_input(typed);
+                match self.stack.evaluate_in(self.context, o) {
                     Ok(new_stack) => {
                         self.stack = new_stack;
                         self.history.push(name.into());
@@ -111,10 +240,54 @@ impl WasmEnvironment {
         }
     }
 
-    fn push_from_input(&mut self) {
+    /// Evaluates the register operation on the register named by the input rather than pushing
+    /// it, without the character that triggered the operation
+    fn evaluate_register(
+        &mut self,
+        operation: RegisterOperation,
+        name: &str,
+        trigger: Option<char>,
+    ) {
         let value = self.input_element.value();
-        let cleaned = value.trim_end_matches(|c| !"0123456789".contains(c));
-        match cleaned.parse() {
+        let register = value.strip_suffix(|c| Some(c) == trigger).unwrap_or(&value).trim();
+        let registers = &mut self.registers;
+        match self.stack.evaluate_in(self.context, |stack| operation(registers, register, stack)) {
+            Ok(new_stack) => {
+                self.stack = new_stack;
+                self.history.push(format!("{name} {register}"));
+            }
+            Err(e) => console::error_1(&format!("failed operation {e}").into()),
+        }
+        self.clear_print();
+    }
+
+    /// Radix of the literal being typed, such as `0x1` or `3#12`, before the character that was
+    /// just typed
+    fn literal_radix(&self) -> Option<u32> {
+        let value = self.input_element.value();
+        let mut entered = value.chars();
+        entered.next_back();
+        let entered = entered.as_str().trim_start_matches('-');
+        match entered.get(..2) {
+            Some("0x" | "0X") => Some(16),
+            Some("0o" | "0O") => Some(8),
+            Some("0b" | "0B") => Some(2),
+            _ => entered
+                .split_once('#')
+                .and_then(|(radix, _)| radix.parse().ok())
+                .filter(|radix| (2..=36).contains(radix)),
+        }
+    }
+
+    /// Pushes the number typed in the input, without the character that triggered the push
+    fn push_from_input(&mut self, trigger: Option<char>) {
+        let value = self.input_element.value();
+        let cleaned = value.strip_suffix(|c| Some(c) == trigger).unwrap_or(&value).trim();
+        let number = match radix::parse(cleaned) {
+            Err(RadixError::Prefix) => notation::parse(cleaned).ok_or("invalid number".to_owned()),
+            number => number.map_err(|e| format!("{e}")),
+        };
+        match number {
             Ok(number) => {
                 self.stack.push(number).expect("failed pushing number");
                 self.history.push(cleaned.into());
@@ -128,7 +301,11 @@ impl WasmEnvironment {
 
 enum WasmInputAction<'a> {
     Operation(fn(OpStack<S>) -> Result<OpStack<S>, OperationError>, &'a str),
+    Register(RegisterOperation, &'a str),
+    ClearRegisters,
     Push,
+    CycleNotation,
+    CycleRadix,
     Ignore,
     Unknown(char),
 }